@@ -1,2 +1,7 @@
 #!/bin/bash
-exec cargo run --bin "$1" -- "src/bin/$1/${2:-input}.txt" "${@:3}"
+input="src/bin/$1/${2:-input}.txt"
+if [[ -z "$2" && ! -e "$input" ]]; then
+    # the real input gets downloaded into the cache instead
+    exec cargo run --bin "$1" -- "${@:3}"
+fi
+exec cargo run --bin "$1" -- "$input" "${@:3}"
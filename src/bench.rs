@@ -0,0 +1,211 @@
+//! Timing each day's parse and parts separately, and keeping track of whether they get slower.
+
+use crate::solution::DynSolution;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    collections::BTreeMap,
+    fmt::{self, Display},
+    fs,
+    path::Path,
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, Instant},
+};
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED_BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// The system allocator, but counting how many allocations there are and how big they are.
+/// Allocations only get counted in binaries that use this as their `#[global_allocator]`.
+pub struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size() as u64, Ordering::Relaxed);
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        // growing in place still counts, since it's usually a Vec that's run out of room
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size as u64, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+fn allocation_counts() -> (u64, u64) {
+    (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        ALLOCATED_BYTES.load(Ordering::Relaxed),
+    )
+}
+
+/// Times and allocations from running one phase (parsing or a part) a few times over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub mean_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+    /// Per run, which should be the same every time.
+    pub allocations: u64,
+    pub allocated_bytes: u64,
+}
+
+impl Stats {
+    fn new(mut times: Vec<Duration>, allocations: u64, allocated_bytes: u64) -> Stats {
+        times.sort();
+        let runs = times.len().max(1) as u64;
+        let nanos = |time: Duration| time.as_nanos() as u64;
+        // nearest rank, so with few runs this is just the slowest one
+        let p95_index = (times.len() * 95).div_ceil(100).saturating_sub(1);
+        Stats {
+            mean_ns: times.iter().map(|&time| nanos(time)).sum::<u64>() / runs,
+            median_ns: times.get(times.len() / 2).map_or(0, |&time| nanos(time)),
+            p95_ns: times.get(p95_index).map_or(0, |&time| nanos(time)),
+            allocations: allocations / runs,
+            allocated_bytes: allocated_bytes / runs,
+        }
+    }
+}
+
+/// How long each phase of a day takes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayStats {
+    pub parse: Stats,
+    pub part_1: Stats,
+    pub part_2: Stats,
+}
+
+impl DayStats {
+    pub fn phases(&self) -> [(&'static str, &Stats); 3] {
+        [
+            ("parse", &self.parse),
+            ("part 1", &self.part_1),
+            ("part 2", &self.part_2),
+        ]
+    }
+}
+
+/// Run `f` and find out how long it took and how much it allocated.
+fn measure<T>(f: impl FnOnce() -> Result<T>) -> Result<(T, Duration, (u64, u64))> {
+    let (allocations, allocated_bytes) = allocation_counts();
+    let start = Instant::now();
+    let result = f()?;
+    let time = start.elapsed();
+    let (allocations_after, allocated_bytes_after) = allocation_counts();
+    Ok((
+        result,
+        time,
+        (
+            allocations_after - allocations,
+            allocated_bytes_after - allocated_bytes,
+        ),
+    ))
+}
+
+/// Parse `input` and answer both parts `runs` times over, timing each phase separately.
+pub fn bench(solution: &dyn DynSolution, input: &str, runs: usize) -> Result<DayStats> {
+    let mut times = [(); 3].map(|()| Vec::with_capacity(runs));
+    let mut allocations = [(0, 0); 3];
+    for _ in 0..runs.max(1) {
+        let (parsed, parse_time, parse_allocations) = measure(|| solution.parse(input))?;
+        let (_, part_1_time, part_1_allocations) = measure(|| solution.part_1(&*parsed))?;
+        let (_, part_2_time, part_2_allocations) = measure(|| solution.part_2(&*parsed))?;
+        for (phase, (time, (count, bytes))) in [
+            (parse_time, parse_allocations),
+            (part_1_time, part_1_allocations),
+            (part_2_time, part_2_allocations),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            times[phase].push(time);
+            allocations[phase].0 += count;
+            allocations[phase].1 += bytes;
+        }
+    }
+    let [parse, part_1, part_2] = times;
+    let [parse_allocations, part_1_allocations, part_2_allocations] = allocations;
+    Ok(DayStats {
+        parse: Stats::new(parse, parse_allocations.0, parse_allocations.1),
+        part_1: Stats::new(part_1, part_1_allocations.0, part_1_allocations.1),
+        part_2: Stats::new(part_2, part_2_allocations.0, part_2_allocations.1),
+    })
+}
+
+/// Results for every day that was benchmarked, saved so a later run can be compared with it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: BTreeMap<u32, DayStats>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Baseline> {
+        let json = fs::read_to_string(path)
+            .with_context(|| format!("couldn't read baseline {}", path.display()))?;
+        serde_json::from_str(&json).with_context(|| format!("{} isn't a baseline", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)? + "\n")
+            .with_context(|| format!("couldn't write baseline {}", path.display()))
+    }
+
+    /// Every phase whose median time has gone up by more than `threshold` (so 0.1 is 10%)
+    /// since the baseline. Days that aren't in both are left out.
+    pub fn regressions(&self, current: &Baseline, threshold: f64) -> Vec<Regression> {
+        let mut regressions = Vec::new();
+        for (&day, stats) in &current.days {
+            let Some(baseline_stats) = self.days.get(&day) else {
+                continue;
+            };
+            for ((phase, stats), (_, baseline_stats)) in
+                stats.phases().into_iter().zip(baseline_stats.phases())
+            {
+                let ratio = stats.median_ns as f64 / baseline_stats.median_ns.max(1) as f64;
+                if ratio > 1.0 + threshold {
+                    regressions.push(Regression {
+                        day,
+                        phase,
+                        baseline_ns: baseline_stats.median_ns,
+                        current_ns: stats.median_ns,
+                    });
+                }
+            }
+        }
+        regressions
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Regression {
+    pub day: u32,
+    pub phase: &'static str,
+    pub baseline_ns: u64,
+    pub current_ns: u64,
+}
+
+impl Display for Regression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {} {} got slower: {:?} -> {:?}",
+            self.day,
+            self.phase,
+            Duration::from_nanos(self.baseline_ns),
+            Duration::from_nanos(self.current_ns)
+        )
+    }
+}
@@ -0,0 +1,159 @@
+//! Running every day at once, to check that they all still work.
+
+use anyhow::{anyhow, Result};
+use aoc_2024::{
+    days,
+    input::{find_day_input, sidecar_args, Input, SESSION_VAR},
+    solution::{OutputFormat, Record},
+};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+enum Status {
+    Ok([Record; 2]),
+    NoInput,
+    Failed(String),
+}
+
+struct DayRun {
+    day: u32,
+    status: Status,
+    time: Duration,
+}
+
+fn run_day(day: u32, input_name: &str) -> DayRun {
+    let start = Instant::now();
+    let answers = || -> Result<Option<[Record; 2]>> {
+        let Some(path) = find_day_input(day, input_name)? else {
+            return Ok(None);
+        };
+        let solution = days::get(day, &sidecar_args(&path)?)?;
+        let parsed = solution.parse(&Input::Path(path).read_to_string()?)?;
+        Ok(Some([
+            Record::solve(&*solution, &*parsed, day, 1)?,
+            Record::solve(&*solution, &*parsed, day, 2)?,
+        ]))
+    };
+    let status = match answers() {
+        Ok(Some(answers)) => Status::Ok(answers),
+        Ok(None) => Status::NoInput,
+        Err(err) => Status::Failed(format!("{err:#}")),
+    };
+    DayRun {
+        day,
+        status,
+        time: start.elapsed(),
+    }
+}
+
+/// Some answers are pictures, which don't fit in a table.
+fn summarize(answer: &str) -> String {
+    match answer.trim_end().lines().count() {
+        0 | 1 => answer.trim_end().into(),
+        lines => format!("({lines} lines)"),
+    }
+}
+
+pub fn main(args: impl Iterator<Item = String>, format: OutputFormat) -> Result<()> {
+    let mut parallel = false;
+    let mut input_name = "input".to_string();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--parallel" => parallel = true,
+            "--input" => {
+                input_name = args
+                    .next()
+                    .ok_or_else(|| anyhow!("--input needs a name, like example"))?
+            }
+            _ => return Err(anyhow!("don't know what {arg} means for aoc all")),
+        }
+    }
+
+    let runs: Vec<DayRun> = thread::scope(|scope| {
+        let run = |day| {
+            let input_name = &input_name;
+            scope.spawn(move || run_day(day, input_name))
+        };
+        let join = |handle: thread::ScopedJoinHandle<'_, DayRun>, day| {
+            handle.join().unwrap_or_else(|_| DayRun {
+                day,
+                status: Status::Failed("panicked".into()),
+                time: Duration::ZERO,
+            })
+        };
+        if parallel {
+            let handles: Vec<_> = days::IMPLEMENTED.map(|day| (run(day), day)).collect();
+            handles
+                .into_iter()
+                .map(|(handle, day)| join(handle, day))
+                .collect()
+        } else {
+            // still on their own threads, so that a panic only takes out one day
+            days::IMPLEMENTED.map(|day| join(run(day), day)).collect()
+        }
+    });
+
+    let failures = match format {
+        OutputFormat::Text => print_table(&runs),
+        OutputFormat::Json => print_records(&runs),
+    };
+    if failures > 0 {
+        return Err(anyhow!("{failures} day(s) failed"));
+    }
+    if runs.iter().all(|run| matches!(run.status, Status::NoInput)) {
+        return Err(anyhow!(
+            "couldn't find src/bin/NN/{input_name}.txt for any day \
+             (set {SESSION_VAR} to download the real inputs)"
+        ));
+    }
+    Ok(())
+}
+
+/// Returns how many days failed.
+fn print_table(runs: &[DayRun]) -> usize {
+    println!(
+        "{:>3}  {:<20}  {:<20}  {:>12}  status",
+        "day", "part 1", "part 2", "time"
+    );
+    let mut failures = 0;
+    for DayRun { day, status, time } in runs {
+        let time = format!("{time:.3?}");
+        match status {
+            Status::Ok([part_1, part_2]) => println!(
+                "{day:>3}  {:<20}  {:<20}  {time:>12}  ok",
+                summarize(&part_1.answer),
+                summarize(&part_2.answer)
+            ),
+            Status::NoInput => println!("{day:>3}  {:<20}  {:<20}  {:>12}  no input", "", "", ""),
+            Status::Failed(err) => {
+                failures += 1;
+                println!("{day:>3}  {:<20}  {:<20}  {time:>12}  error: {err}", "", "");
+            }
+        }
+    }
+    failures
+}
+
+/// Only answers go to stdout, so that every line there is a record.
+/// Returns how many days failed.
+fn print_records(runs: &[DayRun]) -> usize {
+    let mut failures = 0;
+    for DayRun { day, status, .. } in runs {
+        match status {
+            Status::Ok(records) => {
+                for record in records {
+                    record.print(OutputFormat::Json);
+                }
+            }
+            Status::NoInput => eprintln!("day {day}: no input"),
+            Status::Failed(err) => {
+                failures += 1;
+                eprintln!("day {day}: error: {err}");
+            }
+        }
+    }
+    failures
+}
@@ -0,0 +1,24 @@
+use anyhow::{anyhow, Result};
+use aoc_2024::{days, solution::OutputFormat};
+use std::env;
+
+mod all;
+
+const USAGE: &str = "usage: aoc [--json] <day> [input file] [options...]
+       aoc [--json] <day> [input file] --help
+       aoc [--json] all [--parallel] [--input NAME]";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1).peekable();
+    // AOC_OUTPUT works too, but the flag is easier to remember
+    let format = if args.next_if_eq("--json").is_some() {
+        OutputFormat::Json
+    } else {
+        OutputFormat::from_env()?
+    };
+    let day = args.next().ok_or_else(|| anyhow!(USAGE))?;
+    if day == "all" {
+        return all::main(args, format);
+    }
+    days::main(day.parse()?, args, format)
+}
@@ -0,0 +1,120 @@
+use anyhow::{anyhow, Result};
+use aoc_2024::{
+    bench::{bench, Baseline, CountingAllocator},
+    days,
+    input::{find_day_input, sidecar_args, Input, SESSION_VAR},
+};
+use std::{env, path::PathBuf, time::Duration};
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "usage: bench [--runs N] [--input NAME] [--save BASELINE.json] \
+                     [--baseline BASELINE.json] [--threshold PERCENT] [days...]";
+
+struct Options {
+    runs: usize,
+    input_name: String,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+    days: Vec<u32>,
+}
+
+fn parse_options() -> Result<Options> {
+    let mut options = Options {
+        runs: 10,
+        input_name: "input".into(),
+        save: None,
+        baseline: None,
+        threshold: 10.0,
+        days: Vec::new(),
+    };
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| anyhow!("{arg} needs a value\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "--runs" => options.runs = value()?.parse()?,
+            "--input" => options.input_name = value()?,
+            "--save" => options.save = Some(value()?.into()),
+            "--baseline" => options.baseline = Some(value()?.into()),
+            "--threshold" => options.threshold = value()?.parse()?,
+            "--help" => return Err(anyhow!(USAGE)),
+            day => options.days.push(
+                day.parse()
+                    .map_err(|_| anyhow!("{day} isn't a day\n{USAGE}"))?,
+            ),
+        }
+    }
+    if options.days.is_empty() {
+        // everything there's an input for
+        for day in days::IMPLEMENTED {
+            if find_day_input(day, &options.input_name)?.is_some() {
+                options.days.push(day);
+            }
+        }
+        if options.days.is_empty() {
+            return Err(anyhow!(
+                "couldn't find src/bin/NN/{}.txt for any day \
+                 (set {SESSION_VAR} to download the real inputs)",
+                options.input_name
+            ));
+        }
+    }
+    Ok(options)
+}
+
+fn main() -> Result<()> {
+    let options = parse_options()?;
+    if cfg!(debug_assertions) {
+        eprintln!("this is a debug build, so the times won't mean much (try --release)");
+    }
+
+    let mut results = Baseline::default();
+    println!(
+        "{:>3}  {:<6}  {:>12}  {:>12}  {:>12}  {:>10}  {:>12}",
+        "day", "phase", "mean", "median", "p95", "allocs", "bytes"
+    );
+    for &day in &options.days {
+        let path = find_day_input(day, &options.input_name)?
+            .ok_or_else(|| anyhow!("couldn't find src/bin/{day:02}/{}.txt", options.input_name))?;
+        let solution = days::get(day, &sidecar_args(&path)?)?;
+        let input = Input::Path(path).read_to_string()?;
+        let stats = bench(&*solution, &input, options.runs)?;
+        for (phase, stats) in stats.phases() {
+            let time = |nanos| format!("{:.3?}", Duration::from_nanos(nanos));
+            println!(
+                "{day:>3}  {phase:<6}  {:>12}  {:>12}  {:>12}  {:>10}  {:>12}",
+                time(stats.mean_ns),
+                time(stats.median_ns),
+                time(stats.p95_ns),
+                stats.allocations,
+                stats.allocated_bytes,
+            );
+        }
+        results.days.insert(day, stats);
+    }
+
+    if let Some(path) = &options.save {
+        results.save(path)?;
+    }
+    if let Some(path) = &options.baseline {
+        let regressions = Baseline::load(path)?.regressions(&results, options.threshold / 100.0);
+        if !regressions.is_empty() {
+            println!();
+            for regression in &regressions {
+                println!("{regression}");
+            }
+            return Err(anyhow!(
+                "{} phase(s) more than {}% slower than {}",
+                regressions.len(),
+                options.threshold,
+                path.display()
+            ));
+        }
+    }
+    Ok(())
+}
@@ -2,6 +2,28 @@ use crate::input_buf_read;
 use anyhow::Result;
 use std::io::{self, BufRead};
 
+mod connectivity;
+mod direction_set;
+mod grid;
+mod grid_graph;
+mod point;
+mod regions;
+mod shortcuts;
+mod sparse_grid;
+mod tile;
+mod topology;
+
+pub use connectivity::{Blocker, Connectivity};
+pub use direction_set::DirectionSet;
+pub use grid::Grid;
+pub use grid_graph::{GridGraph, Orientation};
+pub use point::{Point, Vector};
+pub use regions::{Adjacency, Region, Regions};
+pub use shortcuts::shortcut_savings;
+pub use sparse_grid::{CellSet, SparseGrid};
+pub use tile::{ParseError, Tile};
+pub use topology::Topology;
+
 mod private {
     pub trait Sealed {}
 }
@@ -47,8 +69,9 @@ impl BoardExt for Board {
     }
 }
 
-pub fn input_board() -> Result<Board> {
-    Board::read(input_buf_read()?).map_err(Into::into)
+/// The input for a day as a board, found the same way as [`input_buf_read`].
+pub fn input_board(day: u32) -> Result<Board> {
+    Board::read(input_buf_read(day)?).map_err(Into::into)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -71,6 +94,25 @@ impl Direction {
         Direction::West,
     ];
 
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::Northeast,
+        Direction::Southeast,
+        Direction::Southwest,
+        Direction::Northwest,
+    ];
+
+    /// Clockwise, starting from north.
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::Northeast,
+        Direction::East,
+        Direction::Southeast,
+        Direction::South,
+        Direction::Southwest,
+        Direction::West,
+        Direction::Northwest,
+    ];
+
     pub fn do_move(self, (i, j): (usize, usize)) -> Option<(usize, usize)> {
         use Direction::*;
         match self {
@@ -126,4 +168,222 @@ impl Direction {
             Southeast => Northeast,
         }
     }
+
+    /// Turn clockwise by `steps` eighths of a full turn (45° each).
+    /// Negative steps turn counter-clockwise.
+    pub fn rotate(self, steps: i32) -> Direction {
+        let index = Direction::ALL
+            .iter()
+            .position(|&dir| dir == self)
+            .expect("ALL has every direction");
+        // rem_euclid can't be negative, so the cast is fine
+        Direction::ALL[(index + steps.rem_euclid(8) as usize) % 8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::{prelude::*, sample::select};
+
+    fn direction() -> impl Strategy<Value = Direction> {
+        select(Direction::ALL.to_vec())
+    }
+
+    fn cardinal() -> impl Strategy<Value = Direction> {
+        select(Direction::CARDINAL.to_vec())
+    }
+
+    /// Mostly positions near the edges, since that's where things go wrong.
+    fn coordinate() -> impl Strategy<Value = usize> {
+        prop_oneof![0..3usize, usize::MAX - 2..=usize::MAX, any::<usize>()]
+    }
+
+    /// Rows of anything but line breaks, not necessarily all the same length.
+    fn rows() -> impl Strategy<Value = Vec<Vec<u8>>> {
+        prop::collection::vec(
+            prop::collection::vec(
+                any::<u8>().prop_filter("no line breaks", |&b| b != b'\n' && b != b'\r'),
+                0..8,
+            ),
+            0..8,
+        )
+    }
+
+    fn board(rows: &[Vec<u8>]) -> Board {
+        rows.iter()
+            .map(|row| row.clone().into_boxed_slice())
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn reverse_twice_is_the_same(dir in direction()) {
+            prop_assert_eq!(dir.reverse().reverse(), dir);
+        }
+
+        #[test]
+        fn clockwise_four_times_is_the_same(dir in direction()) {
+            prop_assert_eq!(dir.clockwise().clockwise().clockwise().clockwise(), dir);
+        }
+
+        #[test]
+        fn cardinals_stay_cardinal(dir in cardinal()) {
+            prop_assert!(Direction::CARDINAL.contains(&dir.clockwise()));
+            prop_assert!(Direction::CARDINAL.contains(&dir.counter_clockwise()));
+            prop_assert!(Direction::CARDINAL.contains(&dir.reverse()));
+        }
+
+        #[test]
+        fn counter_clockwise_undoes_clockwise(dir in direction()) {
+            prop_assert_eq!(dir.clockwise().counter_clockwise(), dir);
+            prop_assert_eq!(dir.counter_clockwise().clockwise(), dir);
+        }
+
+        #[test]
+        fn two_quarter_turns_is_reverse(dir in direction()) {
+            prop_assert_eq!(dir.clockwise().clockwise(), dir.reverse());
+            prop_assert_eq!(dir.counter_clockwise().counter_clockwise(), dir.reverse());
+        }
+
+        #[test]
+        fn rotate_agrees_with_turning(dir in direction(), steps in -20..20i32) {
+            prop_assert_eq!(dir.rotate(2), dir.clockwise());
+            prop_assert_eq!(dir.rotate(-2), dir.counter_clockwise());
+            prop_assert_eq!(dir.rotate(4), dir.reverse());
+            prop_assert_eq!(dir.rotate(steps).rotate(-steps), dir);
+        }
+
+        #[test]
+        fn moving_back_returns_to_start(dir in direction(), i in coordinate(), j in coordinate()) {
+            if let Some(moved) = dir.do_move((i, j)) {
+                prop_assert_ne!(moved, (i, j));
+                prop_assert_eq!(dir.reverse().do_move(moved), Some((i, j)));
+            }
+        }
+
+        #[test]
+        fn moves_only_fail_at_the_edges(dir in direction(), i in coordinate(), j in coordinate()) {
+            let Vector { i: di, j: dj } = dir.into();
+            let at_edge = |x: usize, dx: i64| {
+                (dx < 0 && x == 0) || (dx > 0 && x == usize::MAX)
+            };
+            prop_assert_eq!(dir.do_move((i, j)).is_none(), at_edge(i, di) || at_edge(j, dj));
+        }
+
+        #[test]
+        fn read_handles_either_line_ending(
+            rows in rows(),
+            line_ending in select(vec!["\n", "\r\n"]),
+            trailing in any::<bool>(),
+        ) {
+            let mut input = rows.join(line_ending.as_bytes());
+            if trailing && !rows.is_empty() {
+                input.extend_from_slice(line_ending.as_bytes());
+            }
+            let read = Board::read(&input[..]).unwrap();
+            // a blank last line without a line break after it can't be told apart from no line at all
+            let expected = if !trailing && rows.last().is_some_and(|row| row.is_empty()) {
+                &rows[..rows.len() - 1]
+            } else {
+                &rows[..]
+            };
+            prop_assert_eq!(read, board(expected));
+        }
+
+        #[test]
+        fn get_at_matches_indexing(rows in rows(), i in 0..10usize, j in 0..10usize) {
+            let board = board(&rows);
+            let expected = rows.get(i).and_then(|row| row.get(j)).copied();
+            prop_assert_eq!(board.get_at((i, j)), expected);
+            prop_assert_eq!(board.get_at(None), None);
+        }
+
+        #[test]
+        fn get_at_past_the_edges(rows in rows(), dir in direction(), i in coordinate(), j in coordinate()) {
+            let board = board(&rows);
+            // off the top or left edge comes through as None, and the rest are just too big
+            let pos = dir.do_move((i, j));
+            if let Some((i, j)) = pos {
+                if i >= rows.len() || j >= rows[i].len() {
+                    prop_assert_eq!(board.get_at(pos), None);
+                }
+            } else {
+                prop_assert_eq!(board.get_at(pos), None);
+            }
+        }
+
+        #[test]
+        fn find_tile_finds_the_first_one(rows in rows(), target in any::<u8>()) {
+            let board = board(&rows);
+            let expected = rows.iter().enumerate().find_map(|(i, row)| {
+                row.iter().position(|&tile| tile == target).map(|j| (i, j))
+            });
+            prop_assert_eq!(board.find_tile(target), expected);
+            if let Some(pos) = expected {
+                prop_assert_eq!(board.get_at(pos), Some(target));
+            }
+        }
+    }
+
+    #[test]
+    fn read_strips_carriage_returns() {
+        let board = Board::read(&b"#.\r\n.#\r\n"[..]).unwrap();
+        assert_eq!(board, board_of(&["#.", ".#"]));
+        // but only right before a line break
+        let board = Board::read(&b"#\r.\n"[..]).unwrap();
+        assert_eq!(board, board_of(&["#\r."]));
+    }
+
+    #[test]
+    fn get_at_and_find_tile_on_empty_boards() {
+        for board in [board_of(&[]), board_of(&[""]), board_of(&["", ""])] {
+            assert_eq!(board.get_at((0, 0)), None);
+            assert_eq!(board.find_tile(b'#'), None);
+        }
+    }
+
+    #[test]
+    fn find_tile_on_ragged_boards() {
+        let board = board_of(&["..", ".", "...#"]);
+        assert_eq!(board.find_tile(b'#'), Some((2, 3)));
+        assert_eq!(board.get_at((1, 1)), None);
+        assert_eq!(board.get_at((2, 3)), Some(b'#'));
+    }
+
+    #[test]
+    fn boards_and_grids() {
+        let board = board_of(&["#.", ".#"]);
+        let grid = Grid::try_from(board.clone()).unwrap();
+        assert_eq!(Board::from(grid), board);
+
+        // like an input with a blank line at the end
+        let err = Grid::try_from(board_of(&["#.", ".#", ""])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            Grid::<u8>::parse("#.\n.#\n\n").unwrap_err().to_string()
+        );
+
+        // every row is still there even when they're empty
+        let grid = Grid::try_from(board_of(&["", "", ""])).unwrap();
+        assert_eq!(grid.rows().len(), 3);
+        assert_eq!(Board::from(grid), board_of(&["", "", ""]));
+    }
+
+    #[test]
+    fn empty_wrapping_boards() {
+        for (width, height) in [(0, 0), (0, 3), (3, 0)] {
+            let topology = Topology::Wrapping { width, height };
+            assert_eq!(topology.size(), Some((width, height)));
+            assert_eq!(topology.resolve(Point::ORIGIN), None);
+            assert_eq!(topology.step(Point::ORIGIN, Direction::East), None);
+        }
+        assert_eq!(Topology::Infinite.size(), None);
+    }
+
+    fn board_of(rows: &[&str]) -> Board {
+        rows.iter()
+            .map(|row| row.as_bytes().to_vec().into_boxed_slice())
+            .collect()
+    }
 }
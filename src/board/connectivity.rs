@@ -0,0 +1,233 @@
+use super::{Direction, DirectionSet, Point, Topology};
+
+/// For when obstacles turn up on a board one at a time, and the question is which one
+/// finally cuts two places off from each other, like day 18's falling bytes.
+///
+/// Rather than searching again after every obstacle, this drops all of them at once and
+/// then takes them away again in reverse order, joining up the cells that each one frees.
+/// The obstacle whose removal first connects the two places is the one that cut them off.
+///
+/// ```
+/// # use aoc_2024::board::{Connectivity, Point, Topology};
+/// let board = Topology::Bounded { width: 3, height: 3 };
+/// let wall = [(0, 1), (1, 1), (2, 1), (1, 2)].map(|(i, j)| Point::new(i, j));
+/// let blocker = Connectivity::new(board)
+///     .first_blocker(Point::new(0, 0), Point::new(2, 2), wall)
+///     .unwrap();
+/// assert_eq!((blocker.step, blocker.position), (2, Point::new(2, 1)));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Connectivity {
+    topology: Topology,
+    directions: DirectionSet,
+}
+
+/// The obstacle that cut the board in two, and where it came in the list (from 0).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Blocker {
+    pub step: usize,
+    pub position: Point,
+}
+
+impl Connectivity {
+    /// Cells are connected to the cells orthogonally next to them.
+    pub fn new(topology: Topology) -> Connectivity {
+        Connectivity {
+            topology,
+            directions: Direction::CARDINAL.into_iter().collect(),
+        }
+    }
+
+    /// Which directions count as next to each other. The default is [`Direction::CARDINAL`].
+    pub fn directions(mut self, directions: impl IntoIterator<Item = Direction>) -> Self {
+        self.directions = directions.into_iter().collect();
+        self
+    }
+
+    /// The first of `obstacles` after which there's no way from `source` to `target`,
+    /// or `None` if there's still a way once all of them are there (or there never was one).
+    /// Obstacles that are off the board or already there don't change anything.
+    ///
+    /// # Panics
+    ///
+    /// If the topology is [infinite](Topology::Infinite), since there's always a way round then.
+    pub fn first_blocker(
+        &self,
+        source: Point,
+        target: Point,
+        obstacles: impl IntoIterator<Item = Point>,
+    ) -> Option<Blocker> {
+        let Some((width, height)) = self.topology.size() else {
+            panic!("can't block off an infinite board");
+        };
+        let index = |pos: Point| {
+            let (i, j) = self.topology.resolve(pos)?.to_usize()?;
+            Some(i * width + j)
+        };
+        let (source_index, target_index) = (index(source)?, index(target)?);
+
+        // when each cell gets blocked, if it ever does
+        let mut blocked_at = vec![None; width * height];
+        let mut arrivals = Vec::new();
+        for (step, pos) in obstacles.into_iter().enumerate() {
+            if let Some(cell) = index(pos) {
+                if blocked_at[cell].is_none() {
+                    blocked_at[cell] = Some(step);
+                    arrivals.push((step, pos, cell));
+                }
+            }
+        }
+
+        let mut cells = DisjointSets::new(width * height);
+        let open = |cells: &mut DisjointSets, blocked_at: &[Option<usize>], pos: Point| {
+            let cell = index(pos).expect("only cells on the board get opened");
+            for neighbor in self.topology.neighbors(pos, self.directions.iter()) {
+                if let Some(other) = index(neighbor).filter(|&other| blocked_at[other].is_none()) {
+                    cells.union(cell, other);
+                }
+            }
+        };
+        for i in 0..height {
+            for j in 0..width {
+                if blocked_at[i * width + j].is_none() {
+                    open(&mut cells, &blocked_at, Point::new(i as i64, j as i64));
+                }
+            }
+        }
+        if cells.find(source_index) == cells.find(target_index)
+            && blocked_at[source_index].is_none()
+        {
+            return None;
+        }
+
+        for &(step, position, cell) in arrivals.iter().rev() {
+            blocked_at[cell] = None;
+            open(&mut cells, &blocked_at, position);
+            if blocked_at[source_index].is_none()
+                && blocked_at[target_index].is_none()
+                && cells.find(source_index) == cells.find(target_index)
+            {
+                return Some(Blocker { step, position });
+            }
+        }
+        None
+    }
+}
+
+/// Union-find over cell indices, with path halving and union by size.
+struct DisjointSets {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(len: usize) -> DisjointSets {
+        DisjointSets {
+            parents: (0..len).collect(),
+            sizes: vec![1; len],
+        }
+    }
+
+    fn find(&mut self, mut x: usize) -> usize {
+        while self.parents[x] != x {
+            self.parents[x] = self.parents[self.parents[x]];
+            x = self.parents[x];
+        }
+        x
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.sizes[a] < self.sizes[b] {
+            (a, b) = (b, a);
+        }
+        self.parents[b] = a;
+        self.sizes[a] += self.sizes[b];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(points: &[(i64, i64)]) -> Vec<Point> {
+        points.iter().map(|&(i, j)| Point::new(i, j)).collect()
+    }
+
+    /// A 3 × 3 board, from the top left to the bottom right.
+    fn first_blocker(obstacles: &[(i64, i64)]) -> Option<Blocker> {
+        Connectivity::new(Topology::Bounded {
+            width: 3,
+            height: 3,
+        })
+        .first_blocker(Point::new(0, 0), Point::new(2, 2), points(obstacles))
+    }
+
+    fn blocker(step: usize, (i, j): (i64, i64)) -> Option<Blocker> {
+        Some(Blocker {
+            step,
+            position: Point::new(i, j),
+        })
+    }
+
+    #[test]
+    fn never_cut_off() {
+        assert_eq!(first_blocker(&[]), None);
+        assert_eq!(first_blocker(&[(1, 1), (0, 2), (1, 2)]), None);
+        // off the board
+        assert_eq!(first_blocker(&[(3, 0), (0, -1), (5, 5)]), None);
+    }
+
+    #[test]
+    fn first_obstacle_blocks() {
+        let corridor = Connectivity::new(Topology::Bounded {
+            width: 3,
+            height: 1,
+        });
+        let blocker = corridor.first_blocker(
+            Point::new(0, 0),
+            Point::new(0, 2),
+            points(&[(0, 1), (0, 0), (0, 2)]),
+        );
+        assert_eq!(blocker, self::blocker(0, (0, 1)));
+    }
+
+    #[test]
+    fn obstacle_on_source_or_target() {
+        assert_eq!(first_blocker(&[(1, 1), (0, 0)]), blocker(1, (0, 0)));
+        assert_eq!(first_blocker(&[(1, 1), (2, 2), (0, 0)]), blocker(1, (2, 2)));
+    }
+
+    #[test]
+    fn same_cell_twice() {
+        // the second (0, 1) doesn't count, so it's (1, 0) that closes the corner off
+        assert_eq!(
+            first_blocker(&[(0, 1), (0, 1), (1, 0), (0, 1)]),
+            blocker(2, (1, 0))
+        );
+        // and this time the first (0, 1) is the one that finishes the wall
+        assert_eq!(
+            first_blocker(&[(1, 0), (2, 2), (0, 1), (0, 1)]),
+            blocker(1, (2, 2))
+        );
+        assert_eq!(first_blocker(&[(1, 0), (0, 1), (0, 1)]), blocker(1, (0, 1)));
+    }
+
+    #[test]
+    fn diagonal_gaps() {
+        let wall = [(0, 2), (1, 1), (2, 0)];
+        assert_eq!(first_blocker(&wall), blocker(2, (2, 0)));
+        let kings_moves = Connectivity::new(Topology::Bounded {
+            width: 3,
+            height: 3,
+        })
+        .directions(Direction::ALL);
+        assert_eq!(
+            kings_moves.first_blocker(Point::new(0, 0), Point::new(2, 2), points(&wall)),
+            None
+        );
+    }
+}
@@ -0,0 +1,111 @@
+use super::Direction;
+use std::ops::{BitAnd, BitOr, BitOrAssign, Not};
+
+/// A set of [`Direction`]s packed into one byte, one bit per direction.
+///
+/// Handy for remembering which ways something has gone through each cell of a
+/// [`Grid`](super::Grid) without a set of `(position, direction)` pairs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DirectionSet(u8);
+
+impl DirectionSet {
+    pub const EMPTY: DirectionSet = DirectionSet(0);
+    pub const ALL: DirectionSet = DirectionSet(u8::MAX);
+
+    fn bit(direction: Direction) -> u8 {
+        1 << direction as u8
+    }
+
+    pub fn from_bits(bits: u8) -> DirectionSet {
+        DirectionSet(bits)
+    }
+
+    pub fn bits(self) -> u8 {
+        self.0
+    }
+
+    pub fn contains(self, direction: Direction) -> bool {
+        self.0 & DirectionSet::bit(direction) != 0
+    }
+
+    /// Returns whether `direction` wasn't already in the set, like [`HashSet::insert`](std::collections::HashSet::insert).
+    pub fn insert(&mut self, direction: Direction) -> bool {
+        let was_new = !self.contains(direction);
+        self.0 |= DirectionSet::bit(direction);
+        was_new
+    }
+
+    /// Returns whether `direction` was in the set.
+    pub fn remove(&mut self, direction: Direction) -> bool {
+        let was_there = self.contains(direction);
+        self.0 &= !DirectionSet::bit(direction);
+        was_there
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// In the same order as [`Direction::ALL`].
+    pub fn iter(self) -> impl Iterator<Item = Direction> {
+        Direction::ALL
+            .into_iter()
+            .filter(move |&direction| self.contains(direction))
+    }
+}
+
+impl From<Direction> for DirectionSet {
+    fn from(direction: Direction) -> DirectionSet {
+        DirectionSet(DirectionSet::bit(direction))
+    }
+}
+
+impl FromIterator<Direction> for DirectionSet {
+    fn from_iter<I: IntoIterator<Item = Direction>>(iter: I) -> DirectionSet {
+        let mut set = DirectionSet::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Direction> for DirectionSet {
+    fn extend<I: IntoIterator<Item = Direction>>(&mut self, iter: I) {
+        for direction in iter {
+            self.insert(direction);
+        }
+    }
+}
+
+impl BitOr for DirectionSet {
+    type Output = DirectionSet;
+
+    fn bitor(self, rhs: DirectionSet) -> DirectionSet {
+        DirectionSet(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for DirectionSet {
+    fn bitor_assign(&mut self, rhs: DirectionSet) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for DirectionSet {
+    type Output = DirectionSet;
+
+    fn bitand(self, rhs: DirectionSet) -> DirectionSet {
+        DirectionSet(self.0 & rhs.0)
+    }
+}
+
+impl Not for DirectionSet {
+    type Output = DirectionSet;
+
+    fn not(self) -> DirectionSet {
+        DirectionSet(!self.0)
+    }
+}
@@ -0,0 +1,240 @@
+use super::{Board, Direction, ParseError, Point, Topology};
+use std::{
+    iter,
+    ops::{Index, IndexMut},
+};
+
+/// A rectangular grid of cells, stored contiguously in row-major order.
+///
+/// Positions are `(row, column)`, the same as everywhere else in [`board`](super).
+/// Grids are [bounded](Topology::Bounded) unless they're made [`wrapping`](Grid::wrapping).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    wrapping: bool,
+    cells: Box<[T]>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut((usize, usize)) -> T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            wrapping: false,
+            cells: (0..height)
+                .flat_map(|i| (0..width).map(move |j| (i, j)))
+                .map(&mut f)
+                .collect(),
+        }
+    }
+
+    /// Returns `None` if the rows aren't all the same length.
+    pub fn from_rows<R: IntoIterator<Item = T>>(
+        rows: impl IntoIterator<Item = R>,
+    ) -> Option<Grid<T>> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for row in rows {
+            let row_start = cells.len();
+            cells.extend(row);
+            let row_width = cells.len() - row_start;
+            if *width.get_or_insert(row_width) != row_width {
+                return None;
+            }
+            height += 1;
+        }
+        Some(Grid {
+            width: width.unwrap_or(0),
+            height,
+            wrapping: false,
+            cells: cells.into_boxed_slice(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Make [`neighbors`](Grid::neighbors) wrap around from each edge to the opposite one.
+    pub fn wrapping(mut self) -> Grid<T> {
+        self.wrapping = true;
+        self
+    }
+
+    pub fn topology(&self) -> Topology {
+        let (width, height) = (self.width, self.height);
+        if self.wrapping {
+            Topology::Wrapping { width, height }
+        } else {
+            Topology::Bounded { width, height }
+        }
+    }
+
+    pub fn contains(&self, (i, j): (usize, usize)) -> bool {
+        i < self.height && j < self.width
+    }
+
+    fn index_of(&self, pos: impl Into<Option<(usize, usize)>>) -> Option<usize> {
+        pos.into()
+            .filter(|&pos| self.contains(pos))
+            .map(|(i, j)| i * self.width + j)
+    }
+
+    /// Like [`BoardExt::get_at`](super::BoardExt::get_at), this takes an `Option` so that
+    /// the result of [`Direction::do_move`] can be passed straight in.
+    pub fn get(&self, pos: impl Into<Option<(usize, usize)>>) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: impl Into<Option<(usize, usize)>>) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// # Safety
+    ///
+    /// `pos` must be inside the grid.
+    pub unsafe fn get_unchecked(&self, (i, j): (usize, usize)) -> &T {
+        self.cells.get_unchecked(i * self.width + j)
+    }
+
+    /// # Safety
+    ///
+    /// `pos` must be inside the grid.
+    pub unsafe fn get_unchecked_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        self.cells.get_unchecked_mut(i * self.width + j)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            wrapping: self.wrapping,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /// Every cell along with its position, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.positions().zip(self.cells.iter_mut())
+    }
+
+    pub fn row(&self, i: usize) -> Option<&[T]> {
+        (i < self.height).then(|| &self.cells[i * self.width..(i + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|i| &self.cells[i * self.width..(i + 1) * self.width])
+    }
+
+    pub fn column(&self, j: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let cells = if j < self.width {
+            &self.cells[j..]
+        } else {
+            &[]
+        };
+        cells.iter().step_by(self.width.max(1))
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|j| self.column(j))
+    }
+
+    /// The position of the first cell (in row-major order) that matches.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(pos, _)| pos)
+    }
+
+    /// The cells one step away from `pos` in each of `directions` that are still on the grid,
+    /// going by its [`topology`](Grid::topology).
+    pub fn neighbors(
+        &self,
+        pos: (usize, usize),
+        directions: impl IntoIterator<Item = Direction>,
+    ) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let topology = self.topology();
+        directions.into_iter().filter_map(move |dir| {
+            let new_pos = topology.step(Point::from(pos), dir)?.to_usize()?;
+            self.get(new_pos).map(|cell| (new_pos, cell))
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            wrapping: false,
+            cells: iter::repeat_n(fill, width * height).collect(),
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Like [`BoardExt::find_tile`](super::BoardExt::find_tile).
+    pub fn find(&self, target: &T) -> Option<(usize, usize)> {
+        self.position(|cell| cell == target)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: (usize, usize)) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, pos: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{pos:?} is outside a {width}x{height} grid"))
+    }
+}
+
+/// Boards can have rows of different lengths (like when the input has a blank line at the end),
+/// but grids can't.
+impl TryFrom<Board> for Grid<u8> {
+    type Error = ParseError;
+
+    fn try_from(board: Board) -> Result<Grid<u8>, ParseError> {
+        let expected_width = board.first().map_or(0, |row| row.len());
+        if let Some((i, row)) = board
+            .iter()
+            .enumerate()
+            .find(|(_, row)| row.len() != expected_width)
+        {
+            return Err(ParseError::RaggedRow {
+                line: i + 1,
+                width: row.len(),
+                expected_width,
+            });
+        }
+        Ok(Grid::from_rows(board.into_vec()).expect("the rows are all the same length"))
+    }
+}
+
+impl From<Grid<u8>> for Board {
+    fn from(grid: Grid<u8>) -> Board {
+        grid.rows().map(Box::from).collect()
+    }
+}
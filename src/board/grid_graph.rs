@@ -0,0 +1,216 @@
+use super::{Direction, DirectionSet, Grid, Topology};
+use pathfinding::prelude::components;
+use std::collections::{HashSet, VecDeque};
+
+type StepFn<'a, R> = Box<dyn Fn((usize, usize), (usize, usize)) -> R + 'a>;
+
+/// A board seen as a graph, so it can be handed straight to `pathfinding`.
+///
+/// ```
+/// # use aoc_2024::board::{Grid, GridGraph};
+/// # use pathfinding::prelude::bfs;
+/// let maze = Grid::<u8>::parse("..#\n#..\n").unwrap();
+/// let graph = GridGraph::from_grid(&maze, |&cell| cell != b'#');
+/// let path = bfs(&(0, 0), |&pos| graph.neighbors(pos), |&pos| pos == (1, 2));
+/// assert_eq!(path, Some(vec![(0, 0), (0, 1), (1, 1), (1, 2)]));
+/// ```
+///
+/// Each step costs 1 unless a [`cost`](GridGraph::cost) function says otherwise.
+pub struct GridGraph<'a, C = usize> {
+    topology: Topology,
+    directions: DirectionSet,
+    passable: Box<dyn Fn((usize, usize)) -> bool + 'a>,
+    can_step: StepFn<'a, bool>,
+    cost: StepFn<'a, C>,
+}
+
+/// Where something is and which way it's facing, for when turning around costs something.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Orientation {
+    pub position: (usize, usize),
+    pub direction: Direction,
+}
+
+impl<'a> GridGraph<'a> {
+    /// Every position `passable` says yes to is a node, and there are edges between
+    /// orthogonally adjacent nodes.
+    pub fn new(
+        topology: Topology,
+        passable: impl Fn((usize, usize)) -> bool + 'a,
+    ) -> GridGraph<'a> {
+        GridGraph {
+            topology,
+            directions: Direction::CARDINAL.into_iter().collect(),
+            passable: Box::new(passable),
+            can_step: Box::new(|_, _| true),
+            cost: Box::new(|_, _| 1),
+        }
+    }
+
+    /// Like [`new`](GridGraph::new), but going by what's in each cell of `grid`.
+    pub fn from_grid<T>(grid: &'a Grid<T>, passable: impl Fn(&T) -> bool + 'a) -> GridGraph<'a> {
+        GridGraph::new(grid.topology(), move |pos| passable(&grid[pos]))
+    }
+}
+
+impl<'a, C> GridGraph<'a, C> {
+    /// Which directions a step can go in. The default is [`Direction::CARDINAL`].
+    pub fn directions(mut self, directions: impl IntoIterator<Item = Direction>) -> Self {
+        self.directions = directions.into_iter().collect();
+        self
+    }
+
+    /// Only allow steps from one position to the next that `can_step` says yes to,
+    /// like day 10's trails that have to go up by exactly one each step.
+    pub fn edges(mut self, can_step: impl Fn((usize, usize), (usize, usize)) -> bool + 'a) -> Self {
+        self.can_step = Box::new(can_step);
+        self
+    }
+
+    /// How much each step costs.
+    pub fn cost<D>(
+        self,
+        cost: impl Fn((usize, usize), (usize, usize)) -> D + 'a,
+    ) -> GridGraph<'a, D> {
+        GridGraph {
+            topology: self.topology,
+            directions: self.directions,
+            passable: self.passable,
+            can_step: self.can_step,
+            cost: Box::new(cost),
+        }
+    }
+
+    fn step(&self, from: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let to = self.topology.step(from.into(), direction)?.to_usize()?;
+        ((self.passable)(to) && (self.can_step)(from, to)).then_some(to)
+    }
+
+    /// The positions one step away from `pos`, for unweighted searches like
+    /// `bfs` and `count_paths`.
+    pub fn neighbors(&self, pos: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.directions
+            .iter()
+            .filter_map(move |direction| self.step(pos, direction))
+    }
+
+    /// The positions one step away from `pos` along with what it costs to get there,
+    /// for weighted searches like `astar` and `dijkstra_all`.
+    pub fn successors(
+        &self,
+        pos: (usize, usize),
+    ) -> impl Iterator<Item = ((usize, usize), C)> + '_ {
+        self.neighbors(pos)
+            .map(move |to| (to, (self.cost)(pos, to)))
+    }
+
+    /// Going forward to the next position, or turning 90° either way on the spot for `turn_cost`.
+    /// This is for searches where which way you're facing matters, like day 16's reindeer.
+    pub fn oriented_successors(
+        &self,
+        Orientation {
+            position,
+            direction,
+        }: Orientation,
+        turn_cost: C,
+    ) -> impl Iterator<Item = (Orientation, C)> + '_
+    where
+        C: Clone,
+    {
+        let forward = self.step(position, direction).map(|to| {
+            let state = Orientation {
+                position: to,
+                direction,
+            };
+            (state, (self.cost)(position, to))
+        });
+        let turns = [direction.clockwise(), direction.counter_clockwise()].map(|direction| {
+            let state = Orientation {
+                position,
+                direction,
+            };
+            (state, turn_cost.clone())
+        });
+        forward.into_iter().chain(turns)
+    }
+
+    /// The other way round from [`oriented_successors`](GridGraph::oriented_successors):
+    /// the states one move before this one, and what that move costs.
+    pub fn oriented_predecessors(
+        &self,
+        Orientation {
+            position,
+            direction,
+        }: Orientation,
+        turn_cost: C,
+    ) -> impl Iterator<Item = (Orientation, C)> + '_
+    where
+        C: Clone,
+    {
+        let backward = self
+            .topology
+            .step(position.into(), direction.reverse())
+            .and_then(|from| from.to_usize())
+            .filter(|&from| (self.passable)(from) && (self.can_step)(from, position))
+            .map(|from| {
+                let state = Orientation {
+                    position: from,
+                    direction,
+                };
+                (state, (self.cost)(from, position))
+            });
+        // turning either way can be undone by turning the other way
+        let turns = [direction.clockwise(), direction.counter_clockwise()].map(|direction| {
+            let state = Orientation {
+                position,
+                direction,
+            };
+            (state, turn_cost.clone())
+        });
+        backward.into_iter().chain(turns)
+    }
+
+    /// How many steps it takes to get from `start` to each position, ignoring what steps cost,
+    /// or `None` where it can't be reached.
+    ///
+    /// # Panics
+    ///
+    /// If the topology is [infinite](Topology::Infinite).
+    pub fn distances(&self, start: (usize, usize)) -> Grid<Option<usize>> {
+        let Some((width, height)) = self.topology.size() else {
+            panic!("can't find the distances to everywhere on an infinite graph");
+        };
+        let mut distances = Grid::new(width, height, None);
+        if !distances.contains(start) || !(self.passable)(start) {
+            return distances;
+        }
+        distances[start] = Some(0);
+        let mut to_visit = VecDeque::from([(start, 0)]);
+        while let Some((pos, distance)) = to_visit.pop_front() {
+            for next in self.neighbors(pos) {
+                if distances[next].is_none() {
+                    distances[next] = Some(distance + 1);
+                    to_visit.push_back((next, distance + 1));
+                }
+            }
+        }
+        distances
+    }
+
+    /// Groups of nodes that are connected to each other (ignoring which way steps go).
+    ///
+    /// # Panics
+    ///
+    /// If the topology is [infinite](Topology::Infinite), since then there's no listing every node.
+    pub fn components(&self) -> Vec<HashSet<(usize, usize)>> {
+        let Some((width, height)) = self.topology.size() else {
+            panic!("can't find the components of an infinite graph");
+        };
+        let groups: Vec<Vec<_>> = (0..height)
+            .flat_map(|i| (0..width).map(move |j| (i, j)))
+            .filter(|&pos| (self.passable)(pos))
+            .map(|pos| [pos].into_iter().chain(self.neighbors(pos)).collect())
+            .collect();
+        components(&groups)
+    }
+}
@@ -0,0 +1,224 @@
+use super::Direction;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// A signed position, for when things can wander off the board.
+///
+/// `i` is the row and `j` is the column, the same as the `(i, j)` tuples used everywhere else.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub i: i64,
+    pub j: i64,
+}
+
+/// The difference between two [`Point`]s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vector {
+    pub i: i64,
+    pub j: i64,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { i: 0, j: 0 };
+
+    pub fn new(i: i64, j: i64) -> Point {
+        Point { i, j }
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> u64 {
+        (other - self).manhattan_len()
+    }
+
+    pub fn chebyshev_distance(self, other: Point) -> u64 {
+        (other - self).chebyshev_len()
+    }
+
+    /// Wrap around a `width` by `height` board, so that every coordinate ends up in range.
+    ///
+    /// # Panics
+    ///
+    /// If `width` or `height` isn't positive.
+    pub fn wrap(self, width: i64, height: i64) -> Point {
+        Point {
+            i: self.i.rem_euclid(height),
+            j: self.j.rem_euclid(width),
+        }
+    }
+
+    /// `None` if either coordinate is negative.
+    pub fn to_usize(self) -> Option<(usize, usize)> {
+        Some((self.i.try_into().ok()?, self.j.try_into().ok()?))
+    }
+}
+
+impl Vector {
+    pub const ZERO: Vector = Vector { i: 0, j: 0 };
+
+    pub fn new(i: i64, j: i64) -> Vector {
+        Vector { i, j }
+    }
+
+    pub fn manhattan_len(self) -> u64 {
+        self.i.unsigned_abs() + self.j.unsigned_abs()
+    }
+
+    pub fn chebyshev_len(self) -> u64 {
+        self.i.unsigned_abs().max(self.j.unsigned_abs())
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((i, j): (usize, usize)) -> Point {
+        Point {
+            i: i as i64,
+            j: j as i64,
+        }
+    }
+}
+
+/// This lets a [`Point`] go anywhere a position that might be off the board can,
+/// like [`BoardExt::get_at`](super::BoardExt::get_at).
+impl From<Point> for Option<(usize, usize)> {
+    fn from(point: Point) -> Option<(usize, usize)> {
+        point.to_usize()
+    }
+}
+
+impl From<Direction> for Vector {
+    fn from(direction: Direction) -> Vector {
+        use Direction::*;
+        let (i, j) = match direction {
+            Northwest => (-1, -1),
+            North => (-1, 0),
+            Northeast => (-1, 1),
+            West => (0, -1),
+            East => (0, 1),
+            Southwest => (1, -1),
+            South => (1, 0),
+            Southeast => (1, 1),
+        };
+        Vector { i, j }
+    }
+}
+
+/// Only works for vectors one step away in some direction (including diagonally).
+impl TryFrom<Vector> for Direction {
+    type Error = Vector;
+
+    fn try_from(vector: Vector) -> Result<Direction, Vector> {
+        use Direction::*;
+        Ok(match (vector.i, vector.j) {
+            (-1, -1) => Northwest,
+            (-1, 0) => North,
+            (-1, 1) => Northeast,
+            (0, -1) => West,
+            (0, 1) => East,
+            (1, -1) => Southwest,
+            (1, 0) => South,
+            (1, 1) => Southeast,
+            _ => return Err(vector),
+        })
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Point {
+        Point {
+            i: self.i + rhs.i,
+            j: self.j + rhs.j,
+        }
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vector) -> Point {
+        self + -rhs
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Vector {
+        Vector {
+            i: self.i - rhs.i,
+            j: self.j - rhs.j,
+        }
+    }
+}
+
+impl Add<Direction> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Direction) -> Point {
+        self + Vector::from(rhs)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Vector {
+        Vector {
+            i: self.i + rhs.i,
+            j: self.j + rhs.j,
+        }
+    }
+}
+
+impl AddAssign for Vector {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Vector {
+        self + -rhs
+    }
+}
+
+impl SubAssign for Vector {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector {
+            i: -self.i,
+            j: -self.j,
+        }
+    }
+}
+
+impl Mul<i64> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: i64) -> Vector {
+        Vector {
+            i: self.i * rhs,
+            j: self.j * rhs,
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use super::{Direction, Grid};
+
+/// Which cells count as touching when grouping them into regions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Adjacency {
+    /// Only cells that share an edge.
+    Orthogonal,
+    /// Cells that share a corner as well.
+    Diagonal,
+}
+
+impl Adjacency {
+    pub fn directions(self) -> &'static [Direction] {
+        match self {
+            Adjacency::Orthogonal => &Direction::CARDINAL,
+            Adjacency::Diagonal => &Direction::ALL,
+        }
+    }
+}
+
+/// Every region of a grid: groups of touching cells that all have the same value,
+/// like day 12's garden plots.
+///
+/// ```
+/// # use aoc_2024::board::{Adjacency, Grid};
+/// let garden = Grid::<u8>::parse("AAA\nABA\nAAA\n").unwrap();
+/// let regions = garden.regions(Adjacency::Orthogonal);
+/// let outer = regions.region((0, 0));
+/// assert_eq!((outer.area, outer.perimeter, outer.sides, outer.holes), (8, 16, 8, 1));
+/// assert_eq!(regions.region((1, 1)).bounding_box(), ((1, 1), (1, 1)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Regions {
+    labels: Grid<usize>,
+    regions: Vec<Region>,
+}
+
+/// The shape of one region. Perimeter, sides and corners all go by the edges of the cells,
+/// whichever [`Adjacency`] the region was found with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// The region's first cell in row-major order, for finding out what's in it.
+    pub start: (usize, usize),
+    pub area: usize,
+    /// How many cell edges are between the region and something else (including the edge of the grid).
+    pub perimeter: usize,
+    /// How many straight fences it would take to go all the way round, holes included.
+    pub sides: usize,
+    /// Inside and outside corners both count.
+    pub corners: usize,
+    /// Bits of the rest of the grid that are completely surrounded by the region. Bits that
+    /// only meet at a corner count separately, since the region runs between them.
+    pub holes: usize,
+    top_left: (usize, usize),
+    bottom_right: (usize, usize),
+}
+
+impl Region {
+    /// The top left and bottom right corners (inclusive) of the smallest rectangle
+    /// that holds the whole region.
+    pub fn bounding_box(&self) -> ((usize, usize), (usize, usize)) {
+        (self.top_left, self.bottom_right)
+    }
+}
+
+impl<T: Eq> Grid<T> {
+    /// Group the cells into regions, treating the grid as [bounded](super::Topology::Bounded)
+    /// even if it wraps.
+    ///
+    /// This takes time proportional to the size of the grid, however many regions there are.
+    pub fn regions(&self, adjacency: Adjacency) -> Regions {
+        let mut labels: Grid<Option<usize>> = Grid::new(self.width(), self.height(), None);
+        let mut regions = Vec::new();
+        let mut to_visit = Vec::new();
+        for start in self.positions() {
+            if labels[start].is_some() {
+                continue;
+            }
+            let label = regions.len();
+            let mut region = Region {
+                start,
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                corners: 0,
+                holes: 0,
+                top_left: start,
+                bottom_right: start,
+            };
+            labels[start] = Some(label);
+            to_visit.push(start);
+            while let Some(pos) = to_visit.pop() {
+                region.area += 1;
+                region.top_left.1 = region.top_left.1.min(pos.1);
+                region.bottom_right.0 = region.bottom_right.0.max(pos.0);
+                region.bottom_right.1 = region.bottom_right.1.max(pos.1);
+                for &dir in adjacency.directions() {
+                    let Some(next) = dir.do_move(pos).filter(|&next| self.contains(next)) else {
+                        continue;
+                    };
+                    if labels[next].is_none() && self[next] == self[start] {
+                        labels[next] = Some(label);
+                        to_visit.push(next);
+                    }
+                }
+            }
+            regions.push(region);
+        }
+        let labels = labels.map(|label| label.expect("every cell gets a label"));
+
+        for (pos, &label) in labels.iter() {
+            regions[label].perimeter += Direction::CARDINAL
+                .into_iter()
+                .filter(|dir| labels.get(dir.do_move(pos)) != Some(&label))
+                .count();
+        }
+
+        // Everything else comes from looking at each 2×2 window of cells (hanging off the
+        // edges of the grid too) for each region in it, like in Gray's "bit quads".
+        // Each window where the region has one or three cells has a corner in the middle,
+        // and each window where it has two diagonally opposite cells has two.
+        let mut quads = vec![[0isize; 3]; regions.len()];
+        for i in 0..=labels.height() {
+            for j in 0..=labels.width() {
+                let window = [
+                    i.checked_sub(1).zip(j.checked_sub(1)),
+                    i.checked_sub(1).map(|i| (i, j)),
+                    j.checked_sub(1).map(|j| (i, j)),
+                    Some((i, j)),
+                ]
+                .map(|pos| labels.get(pos).copied());
+                for (k, &label) in window.iter().enumerate() {
+                    // only count each region in the window once
+                    let Some(label) = label.filter(|label| !window[..k].contains(&Some(*label)))
+                    else {
+                        continue;
+                    };
+                    let [nw, ne, sw, se] = window.map(|other| other == Some(label));
+                    match [nw, ne, sw, se]
+                        .into_iter()
+                        .filter(|&inside| inside)
+                        .count()
+                    {
+                        1 => quads[label][0] += 1,
+                        3 => quads[label][1] += 1,
+                        2 if nw == se => quads[label][2] += 1,
+                        _ => {}
+                    }
+                }
+            }
+        }
+        for (region, [ones, threes, diagonals]) in regions.iter_mut().zip(quads) {
+            region.corners = (ones + threes + 2 * diagonals) as usize;
+            // every side of a closed boundary ends at exactly one corner
+            region.sides = region.corners;
+            // The Euler number is components - holes, and there's one component. This is the
+            // one for cells that join up at corners, even for orthogonal regions: where the
+            // region touches itself diagonally it cuts off what's on either side, like a fence.
+            let euler_number = (ones - threes - 2 * diagonals) / 4;
+            region.holes = (1 - euler_number) as usize;
+        }
+
+        Regions { labels, regions }
+    }
+}
+
+impl Regions {
+    pub fn len(&self) -> usize {
+        self.regions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.regions.is_empty()
+    }
+
+    /// Which region the cell at `pos` is in, numbered in the order their first cells come in.
+    pub fn label(&self, pos: (usize, usize)) -> usize {
+        self.labels[pos]
+    }
+
+    /// The region the cell at `pos` is in.
+    pub fn region(&self, pos: (usize, usize)) -> &Region {
+        &self.regions[self.label(pos)]
+    }
+
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Region> {
+        self.regions.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn find_regions(grid: &str, adjacency: Adjacency) -> Regions {
+        Grid::<u8>::parse(grid).unwrap().regions(adjacency)
+    }
+
+    /// Area, perimeter, sides and holes.
+    fn shape(region: &Region) -> (usize, usize, usize, usize) {
+        (region.area, region.perimeter, region.sides, region.holes)
+    }
+
+    #[test]
+    fn one_cell() {
+        let regions = find_regions("A\n", Adjacency::Orthogonal);
+        assert_eq!(regions.len(), 1);
+        let region = regions.region((0, 0));
+        assert_eq!(shape(region), (1, 4, 4, 0));
+        assert_eq!(region.corners, 4);
+        assert_eq!(region.bounding_box(), ((0, 0), (0, 0)));
+    }
+
+    #[test]
+    fn hole() {
+        let regions = find_regions("AAAA\nABBA\nAAAA\n", Adjacency::Orthogonal);
+        assert_eq!(regions.len(), 2);
+        assert_eq!(shape(regions.region((0, 0))), (10, 20, 8, 1));
+        assert_eq!(shape(regions.region((1, 1))), (2, 6, 4, 0));
+        assert_eq!(regions.region((1, 2)).bounding_box(), ((1, 1), (1, 2)));
+    }
+
+    #[test]
+    fn touching_corners() {
+        // from the puzzle: the Bs only touch at a corner, so they're separate regions
+        // and each has 4 sides, and the A round them has 12 sides and 2 holes
+        let regions = find_regions(
+            "AAAAAA\nAAABBA\nAAABBA\nABBAAA\nABBAAA\nAAAAAA\n",
+            Adjacency::Orthogonal,
+        );
+        assert_eq!(regions.len(), 3);
+        assert_eq!(shape(regions.region((0, 0))), (28, 40, 12, 2));
+        assert_eq!(shape(regions.region((1, 3))), (4, 8, 4, 0));
+        assert_eq!(shape(regions.region((3, 1))), (4, 8, 4, 0));
+        assert_ne!(regions.label((1, 3)), regions.label((3, 1)));
+
+        let checkerboard = find_regions("AB\nBA\n", Adjacency::Orthogonal);
+        assert_eq!(checkerboard.len(), 4);
+        for region in checkerboard.iter() {
+            assert_eq!(shape(region), (1, 4, 4, 0));
+        }
+    }
+
+    #[test]
+    fn pinched_holes() {
+        // the A at (2, 1) touches the one at (1, 0) diagonally, which fences the middle B in
+        let regions = find_regions("AAA\nABA\nBAA\n", Adjacency::Orthogonal);
+        assert_eq!(regions.region((0, 0)).holes, 1);
+        assert_eq!(regions.region((1, 1)).holes, 0);
+    }
+
+    #[test]
+    fn diagonal_adjacency() {
+        let checkerboard = find_regions("AB\nBA\n", Adjacency::Diagonal);
+        assert_eq!(checkerboard.len(), 2);
+        assert_eq!(checkerboard.label((0, 0)), checkerboard.label((1, 1)));
+        // the two cells' corners meet in the middle, but they're still both corners
+        let region = checkerboard.region((0, 0));
+        assert_eq!(shape(region), (2, 8, 8, 0));
+        assert_eq!(region.bounding_box(), ((0, 0), (1, 1)));
+
+        // a diamond of As round a B, which is cut off from the other Bs
+        // as far as the As are concerned, even though the Bs are all one region
+        let diamond = find_regions("BAB\nABA\nBAB\n", Adjacency::Diagonal);
+        assert_eq!(diamond.len(), 2);
+        assert_eq!(shape(diamond.region((0, 1))), (4, 16, 16, 1));
+        assert_eq!(shape(diamond.region((0, 0))), (5, 20, 20, 0));
+    }
+}
@@ -0,0 +1,155 @@
+use super::Grid;
+use std::collections::BTreeMap;
+
+/// How much time could be saved by cutting through walls for up to `max_length` steps,
+/// like day 20's cheats. The keys are how many steps get saved, and the values are how many
+/// different shortcuts save that many. Only shortcuts that save at least `min_saving` count.
+///
+/// `from_start` and `to_end` are how far each cell is from the start and from the end
+/// going the normal way (`None` for walls and anywhere else that can't be reached), like
+/// what [`GridGraph::distances`](super::GridGraph::distances) gives. A shortcut goes from
+/// any cell `a` that can be reached to any cell `b` that can reach the end, as long as
+/// they're no more than `max_length` steps apart ignoring walls, so it's a way to the end
+/// taking `from_start[a] + |a - b| + to_end[b]` steps. Every cell within that distance
+/// gets looked at, so this takes time proportional to the size of the grid times `max_length²`.
+///
+/// ```
+/// # use aoc_2024::board::{shortcut_savings, Grid, GridGraph};
+/// let track = Grid::<u8>::parse("...\n##.\n...\n").unwrap();
+/// let graph = GridGraph::from_grid(&track, |&cell| cell == b'.');
+/// let (from_start, to_end) = (graph.distances((0, 0)), graph.distances((2, 0)));
+/// // going straight down from (0, 0) to (2, 0) takes 2 steps instead of 6
+/// assert_eq!(shortcut_savings(&from_start, &to_end, 2, 1)[&4], 1);
+/// ```
+pub fn shortcut_savings(
+    from_start: &Grid<Option<usize>>,
+    to_end: &Grid<Option<usize>>,
+    max_length: usize,
+    min_saving: usize,
+) -> BTreeMap<usize, usize> {
+    let mut savings_counts = BTreeMap::new();
+    let Some(normal_cost) = from_start
+        .iter()
+        .filter_map(|(pos, &from_start)| Some(from_start? + to_end.get(pos).copied().flatten()?))
+        .min()
+    else {
+        return savings_counts;
+    };
+    let Some(budget) = normal_cost.checked_sub(min_saving) else {
+        return savings_counts;
+    };
+
+    // the diamond of offsets around a cell, apart from the cell itself
+    let max_length = max_length as i64;
+    let offsets: Vec<_> = (-max_length..=max_length)
+        .flat_map(|di: i64| {
+            let remaining = max_length - di.abs();
+            (-remaining..=remaining).map(move |dj| (di, dj))
+        })
+        .filter(|&offset| offset != (0, 0))
+        .collect();
+
+    for ((i, j), &from_start) in from_start.iter() {
+        let Some(from_start) = from_start.filter(|&from_start| from_start <= budget) else {
+            continue;
+        };
+        for &(di, dj) in &offsets {
+            let (Ok(b_i), Ok(b_j)) = (
+                usize::try_from(i as i64 + di),
+                usize::try_from(j as i64 + dj),
+            ) else {
+                continue;
+            };
+            let Some(&Some(to_end)) = to_end.get((b_i, b_j)) else {
+                continue;
+            };
+            let cost = from_start + (di.unsigned_abs() + dj.unsigned_abs()) as usize + to_end;
+            if cost <= budget {
+                *savings_counts.entry(normal_cost - cost).or_insert(0) += 1;
+            }
+        }
+    }
+    savings_counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::board::GridGraph;
+
+    /// Distances from the top left and to the bottom left of a track.
+    fn distances(track: &str) -> (Grid<Option<usize>>, Grid<Option<usize>>) {
+        let track = Grid::<u8>::parse(track).unwrap();
+        let graph = GridGraph::from_grid(&track, |&cell| cell == b'.');
+        let end = (track.height() - 1, 0);
+        (graph.distances((0, 0)), graph.distances(end))
+    }
+
+    /// Every pair of cells, the slow way.
+    fn brute_force(
+        from_start: &Grid<Option<usize>>,
+        to_end: &Grid<Option<usize>>,
+        max_length: usize,
+        min_saving: usize,
+    ) -> BTreeMap<usize, usize> {
+        let normal_cost = to_end[(0, 0)].unwrap();
+        let mut savings_counts = BTreeMap::new();
+        for (a, &from_start) in from_start.iter() {
+            for (b, &to_end) in to_end.iter() {
+                let length = a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+                let (Some(from_start), Some(to_end)) = (from_start, to_end) else {
+                    continue;
+                };
+                if a == b || length > max_length {
+                    continue;
+                }
+                let cost = from_start + length + to_end;
+                if cost + min_saving <= normal_cost {
+                    *savings_counts.entry(normal_cost - cost).or_insert(0) += 1;
+                }
+            }
+        }
+        savings_counts
+    }
+
+    const TRACK: &str = "...\n##.\n...\n";
+
+    #[test]
+    fn matches_brute_force() {
+        let (from_start, to_end) = distances(TRACK);
+        assert_eq!(
+            shortcut_savings(&from_start, &to_end, 2, 1),
+            BTreeMap::from([(2, 1), (4, 1)])
+        );
+        // long enough to run off every edge of the grid
+        for max_length in 0..=8 {
+            for min_saving in 0..=7 {
+                assert_eq!(
+                    shortcut_savings(&from_start, &to_end, max_length, min_saving),
+                    brute_force(&from_start, &to_end, max_length, min_saving),
+                    "max_length {max_length}, min_saving {min_saving}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn nothing_to_save() {
+        let (from_start, to_end) = distances(TRACK);
+        // the normal way only takes 6 steps
+        assert!(shortcut_savings(&from_start, &to_end, 4, 7).is_empty());
+        assert!(shortcut_savings(&from_start, &to_end, 0, 1).is_empty());
+        // the normal way is the only way
+        let (from_start, to_end) = distances("...\n");
+        assert!(shortcut_savings(&from_start, &to_end, 2, 0).is_empty());
+    }
+
+    #[test]
+    fn unreachable_end() {
+        // a shortcut would get through the wall, but with no normal way through
+        // there's nothing to compare it with
+        let (from_start, to_end) = distances("..\n##\n..\n");
+        assert_eq!(to_end[(0, 0)], None);
+        assert!(shortcut_savings(&from_start, &to_end, 2, 0).is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+use super::{Direction, Grid, Point, Topology};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashMap},
+    ops::RangeBounds,
+};
+
+/// An unbounded grid where only some cells have anything in them.
+///
+/// Cells can be anywhere, including at negative coordinates, so this is the
+/// [`Topology::Infinite`] counterpart to [`Grid`].
+///
+/// Looking up a cell is a hash lookup. Each row and column is also kept in order,
+/// so scanning along part of one is cheap, and so is finding the bounding box.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point, T>,
+    rows: BTreeMap<i64, BTreeSet<i64>>,
+    columns: BTreeMap<i64, BTreeSet<i64>>,
+}
+
+/// A set of cells, for things like walls or places that have been visited.
+pub type CellSet = SparseGrid<()>;
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            rows: BTreeMap::new(),
+            columns: BTreeMap::new(),
+        }
+    }
+
+    pub fn topology(&self) -> Topology {
+        Topology::Infinite
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Point) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn get(&self, pos: Point) -> Option<&T> {
+        self.cells.get(&pos)
+    }
+
+    pub fn get_mut(&mut self, pos: Point) -> Option<&mut T> {
+        self.cells.get_mut(&pos)
+    }
+
+    /// Returns what used to be there.
+    pub fn insert(&mut self, pos: Point, value: T) -> Option<T> {
+        let old_value = self.cells.insert(pos, value);
+        if old_value.is_none() {
+            self.rows.entry(pos.i).or_default().insert(pos.j);
+            self.columns.entry(pos.j).or_default().insert(pos.i);
+        }
+        old_value
+    }
+
+    pub fn remove(&mut self, pos: Point) -> Option<T> {
+        let old_value = self.cells.remove(&pos)?;
+        // empty rows and columns have to go, or they'd throw off the bounding box
+        fn remove_from(index: &mut BTreeMap<i64, BTreeSet<i64>>, line: i64, k: i64) {
+            if let Some(cells) = index.get_mut(&line) {
+                cells.remove(&k);
+                if cells.is_empty() {
+                    index.remove(&line);
+                }
+            }
+        }
+        remove_from(&mut self.rows, pos.i, pos.j);
+        remove_from(&mut self.columns, pos.j, pos.i);
+        Some(old_value)
+    }
+
+    fn entry(&self, pos: Point) -> (Point, &T) {
+        (pos, &self.cells[&pos])
+    }
+
+    /// Every filled-in cell, in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Point> + '_ {
+        self.rows
+            .iter()
+            .flat_map(|(&i, row)| row.iter().map(move |&j| Point::new(i, j)))
+    }
+
+    /// Every filled-in cell along with what's in it, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().map(|pos| self.entry(pos))
+    }
+
+    /// The filled-in cells in row `i` whose columns are in `columns`, from left to right.
+    pub fn row_range(
+        &self,
+        i: i64,
+        columns: impl RangeBounds<i64>,
+    ) -> impl DoubleEndedIterator<Item = (Point, &T)> {
+        self.rows
+            .get(&i)
+            .map(|row| row.range(columns))
+            .into_iter()
+            .flatten()
+            .map(move |&j| self.entry(Point::new(i, j)))
+    }
+
+    /// The filled-in cells in column `j` whose rows are in `rows`, from top to bottom.
+    pub fn column_range(
+        &self,
+        j: i64,
+        rows: impl RangeBounds<i64>,
+    ) -> impl DoubleEndedIterator<Item = (Point, &T)> {
+        self.columns
+            .get(&j)
+            .map(|column| column.range(rows))
+            .into_iter()
+            .flatten()
+            .map(move |&i| self.entry(Point::new(i, j)))
+    }
+
+    /// The top left and bottom right corners (inclusive) of the smallest rectangle
+    /// that holds every filled-in cell, or `None` if there aren't any.
+    pub fn bounding_box(&self) -> Option<(Point, Point)> {
+        let (&top, _) = self.rows.first_key_value()?;
+        let (&bottom, _) = self.rows.last_key_value()?;
+        let (&left, _) = self.columns.first_key_value()?;
+        let (&right, _) = self.columns.last_key_value()?;
+        Some((Point::new(top, left), Point::new(bottom, right)))
+    }
+
+    /// The filled-in cells one step away from `pos` in each of `directions`.
+    pub fn neighbors(
+        &self,
+        pos: Point,
+        directions: impl IntoIterator<Item = Direction>,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        directions.into_iter().filter_map(move |dir| {
+            let new_pos = pos + dir;
+            self.get(new_pos).map(|value| (new_pos, value))
+        })
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    /// A dense grid covering the [bounding box](SparseGrid::bounding_box), with `background`
+    /// wherever there's nothing, along with where the grid's top left corner is.
+    pub fn to_grid(&self, background: T) -> (Point, Grid<T>) {
+        let Some((top_left, bottom_right)) = self.bounding_box() else {
+            return (Point::ORIGIN, Grid::new(0, 0, background));
+        };
+        let size = bottom_right - top_left;
+        let grid = Grid::from_fn(size.j as usize + 1, size.i as usize + 1, |pos| {
+            self.get(top_left + (Point::from(pos) - Point::ORIGIN))
+                .unwrap_or(&background)
+                .clone()
+        });
+        (top_left, grid)
+    }
+}
+
+impl CellSet {
+    /// Like [`HashSet::insert`](std::collections::HashSet::insert),
+    /// this returns whether `pos` wasn't already in the set.
+    pub fn add(&mut self, pos: Point) -> bool {
+        self.insert(pos, ()).is_none()
+    }
+}
+
+impl<T> Grid<T> {
+    /// Keep the cells that `f` gives something for.
+    pub fn to_sparse<U>(&self, mut f: impl FnMut(&T) -> Option<U>) -> SparseGrid<U> {
+        self.iter()
+            .filter_map(|(pos, cell)| Some((Point::from(pos), f(cell)?)))
+            .collect()
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Point, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(iter: I) -> SparseGrid<T> {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Point, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Point, T)>>(&mut self, iter: I) {
+        for (pos, value) in iter {
+            self.insert(pos, value);
+        }
+    }
+}
+
+impl FromIterator<Point> for CellSet {
+    fn from_iter<I: IntoIterator<Item = Point>>(iter: I) -> CellSet {
+        iter.into_iter().map(|pos| (pos, ())).collect()
+    }
+}
+
+impl Extend<Point> for CellSet {
+    fn extend<I: IntoIterator<Item = Point>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().map(|pos| (pos, ())));
+    }
+}
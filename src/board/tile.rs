@@ -0,0 +1,195 @@
+use super::Grid;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+/// A type that a board's cells can be parsed into, one byte per cell.
+///
+/// Use [`tiles!`](crate::tiles) to declare an enum of tiles.
+pub trait Tile: Copy + Eq {
+    fn from_byte(byte: u8) -> Option<Self>;
+    fn to_byte(self) -> u8;
+}
+
+/// Every byte is a valid tile, so parsing a `Grid<u8>` only checks that it's rectangular.
+impl Tile for u8 {
+    fn from_byte(byte: u8) -> Option<u8> {
+        Some(byte)
+    }
+
+    fn to_byte(self) -> u8 {
+        self
+    }
+}
+
+/// Declare an enum of tiles along with the byte each one is written as.
+///
+/// ```
+/// aoc_2024::tiles! {
+///     pub enum MazeTile {
+///         Wall = b'#',
+///         Open = b'.',
+///         Start = b'S',
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! tiles {
+    (
+        $(#[$meta:meta])*
+        $vis:vis enum $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $byte:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis enum $name {
+            $($(#[$variant_meta])* $variant),*
+        }
+
+        impl $crate::board::Tile for $name {
+            fn from_byte(byte: u8) -> Option<Self> {
+                match byte {
+                    $($byte => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            fn to_byte(self) -> u8 {
+                match self {
+                    $(Self::$variant => $byte,)*
+                }
+            }
+        }
+    };
+}
+
+/// What went wrong parsing a board. Lines and columns count from 1, like an editor would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnknownTile {
+        line: usize,
+        column: usize,
+        byte: u8,
+    },
+    RaggedRow {
+        line: usize,
+        width: usize,
+        expected_width: usize,
+    },
+    MissingTile {
+        byte: u8,
+    },
+    DuplicateTile {
+        line: usize,
+        column: usize,
+        byte: u8,
+        first_line: usize,
+        first_column: usize,
+    },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParseError::UnknownTile { line, column, byte } => write!(
+                f,
+                "line {line}, column {column}: unknown tile '{}'",
+                byte.escape_ascii()
+            ),
+            ParseError::RaggedRow {
+                line,
+                width,
+                expected_width,
+            } => write!(
+                f,
+                "line {line}: row is {width} tiles wide, expected {expected_width}"
+            ),
+            ParseError::MissingTile { byte } => {
+                write!(f, "no '{}' tile on the board", byte.escape_ascii())
+            }
+            ParseError::DuplicateTile {
+                line,
+                column,
+                byte,
+                first_line,
+                first_column,
+            } => write!(
+                f,
+                "line {line}, column {column}: there's already a '{}' tile at line {first_line}, column {first_column}",
+                byte.escape_ascii()
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl<T: Tile> Grid<T> {
+    /// Parse a board, one line per row. `\r\n` line endings are fine.
+    pub fn parse(input: &str) -> Result<Grid<T>, ParseError> {
+        let (grid, []) = Grid::parse_with_unique(input, [])?;
+        Ok(grid)
+    }
+
+    /// Parse a board where each of the `unique` tiles has to appear exactly once,
+    /// and return where they are.
+    #[allow(
+        clippy::type_complexity,
+        reason = "a type alias would just hide what this returns"
+    )]
+    pub fn parse_with_unique<const N: usize>(
+        input: &str,
+        unique: [T; N],
+    ) -> Result<(Grid<T>, [(usize, usize); N]), ParseError> {
+        let mut unique_positions = [None; N];
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let row = line
+                .bytes()
+                .enumerate()
+                .map(|(j, byte)| {
+                    let tile = T::from_byte(byte).ok_or(ParseError::UnknownTile {
+                        line: i + 1,
+                        column: j + 1,
+                        byte,
+                    })?;
+                    if let Some(k) = unique.iter().position(|&u| u == tile) {
+                        if let Some((first_i, first_j)) = unique_positions[k] {
+                            return Err(ParseError::DuplicateTile {
+                                line: i + 1,
+                                column: j + 1,
+                                byte,
+                                first_line: first_i + 1,
+                                first_column: first_j + 1,
+                            });
+                        }
+                        unique_positions[k] = Some((i, j));
+                    }
+                    Ok(tile)
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            if let Some(first_row) = rows.first() {
+                let expected_width = first_row.len();
+                if row.len() != expected_width {
+                    return Err(ParseError::RaggedRow {
+                        line: i + 1,
+                        width: row.len(),
+                        expected_width,
+                    });
+                }
+            }
+            rows.push(row);
+        }
+
+        let mut positions = [(0, 0); N];
+        for ((position, found), tile) in positions.iter_mut().zip(unique_positions).zip(unique) {
+            *position = found.ok_or(ParseError::MissingTile {
+                byte: tile.to_byte(),
+            })?;
+        }
+        let grid = Grid::from_rows(rows).expect("already checked that the rows are all the same");
+        Ok((grid, positions))
+    }
+}
@@ -0,0 +1,95 @@
+use super::{Direction, Point};
+
+/// What happens at the edges of a board.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Topology {
+    /// Anything past the edges is off the board.
+    Bounded { width: usize, height: usize },
+    /// Going off one edge brings you back on at the opposite one, like day 14's robots.
+    /// There's nothing on a wrapping board with no width or height, since there's nowhere to wrap to.
+    Wrapping { width: usize, height: usize },
+    /// There are no edges.
+    Infinite,
+}
+
+impl Topology {
+    /// `(width, height)`, or `None` if the board is [infinite](Topology::Infinite).
+    pub fn size(self) -> Option<(usize, usize)> {
+        match self {
+            Topology::Bounded { width, height } | Topology::Wrapping { width, height } => {
+                Some((width, height))
+            }
+            Topology::Infinite => None,
+        }
+    }
+
+    /// Where `pos` really is, or `None` if it's off the board.
+    pub fn resolve(self, pos: Point) -> Option<Point> {
+        match self {
+            Topology::Bounded { width, height } => {
+                let (i, j) = pos.to_usize()?;
+                (i < height && j < width).then_some(pos)
+            }
+            Topology::Wrapping { width, height } => {
+                (width > 0 && height > 0).then(|| pos.wrap(width as i64, height as i64))
+            }
+            Topology::Infinite => Some(pos),
+        }
+    }
+
+    pub fn contains(self, pos: Point) -> bool {
+        self.resolve(pos) == Some(pos)
+    }
+
+    pub fn step(self, pos: Point, direction: Direction) -> Option<Point> {
+        self.resolve(pos + direction)
+    }
+
+    /// The positions one step away from `pos` in each of `directions` that are still on the board.
+    pub fn neighbors(
+        self,
+        pos: Point,
+        directions: impl IntoIterator<Item = Direction>,
+    ) -> impl Iterator<Item = Point> {
+        directions
+            .into_iter()
+            .filter_map(move |dir| self.step(pos, dir))
+    }
+
+    /// How far apart two points are along each axis, taking the short way round if it wraps.
+    fn axis_distances(self, a: Point, b: Point) -> (u64, u64) {
+        let (di, dj) = ((b.i - a.i).unsigned_abs(), (b.j - a.j).unsigned_abs());
+        match self {
+            Topology::Wrapping { width, height } => {
+                assert!(
+                    width > 0 && height > 0,
+                    "no distances on a {width}x{height} wrapping board"
+                );
+                let (width, height) = (width as u64, height as u64);
+                let (di, dj) = (di % height, dj % width);
+                (di.min(height - di), dj.min(width - dj))
+            }
+            Topology::Bounded { .. } | Topology::Infinite => (di, dj),
+        }
+    }
+
+    /// The number of orthogonal steps between two points on the board.
+    ///
+    /// # Panics
+    ///
+    /// If the board wraps and has no width or height.
+    pub fn manhattan_distance(self, a: Point, b: Point) -> u64 {
+        let (di, dj) = self.axis_distances(a, b);
+        di + dj
+    }
+
+    /// The number of steps between two points on the board, if diagonal steps are allowed.
+    ///
+    /// # Panics
+    ///
+    /// If the board wraps and has no width or height.
+    pub fn chebyshev_distance(self, a: Point, b: Point) -> u64 {
+        let (di, dj) = self.axis_distances(a, b);
+        di.max(dj)
+    }
+}
@@ -0,0 +1,110 @@
+use crate::{
+    input::{sidecar_args, Input},
+    options::{self, OptionSpec, Options},
+    solution::{run, DynSolution, OutputFormat},
+};
+use anyhow::{anyhow, Result};
+use std::ops::RangeInclusive;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+
+/// Every day there's a solution for.
+pub const IMPLEMENTED: RangeInclusive<u32> = 1..=20;
+
+/// The options a day takes, if any.
+pub fn options(day: u32) -> &'static [OptionSpec] {
+    match day {
+        14 => day14::Day14::OPTIONS,
+        18 => day18::Day18::OPTIONS,
+        _ => &[],
+    }
+}
+
+/// Look up the solution for a day.
+/// `extra_args` are whatever came after the input file on the command line,
+/// which have to be the day's [`options`].
+pub fn get(day: u32, extra_args: &[String]) -> Result<Box<dyn DynSolution>> {
+    let options = Options::parse(options(day), extra_args)?;
+    Ok(match day {
+        1 => Box::new(day01::Day01),
+        2 => Box::new(day02::Day02),
+        3 => Box::new(day03::Day03),
+        4 => Box::new(day04::Day04),
+        5 => Box::new(day05::Day05),
+        6 => Box::new(day06::Day06),
+        7 => Box::new(day07::Day07),
+        8 => Box::new(day08::Day08),
+        9 => Box::new(day09::Day09),
+        10 => Box::new(day10::Day10),
+        11 => Box::new(day11::Day11),
+        12 => Box::new(day12::Day12),
+        13 => Box::new(day13::Day13),
+        14 => Box::new(day14::Day14::from_options(&options)?),
+        15 => Box::new(day15::Day15),
+        16 => Box::new(day16::Day16),
+        17 => Box::new(day17::Day17),
+        18 => Box::new(day18::Day18::from_options(&options)?),
+        19 => Box::new(day19::Day19),
+        20 => Box::new(day20::Day20),
+        _ => return Err(anyhow!("no solution for day {day}")),
+    })
+}
+
+/// Entry point for the per-day binaries and `aoc <day>`. `args` are what comes after the day:
+/// the input file (if it isn't the one in the [cache](crate::input::InputCache)) and then
+/// any options, or `--help` anywhere.
+pub fn main(day: u32, args: impl IntoIterator<Item = String>, format: OutputFormat) -> Result<()> {
+    let args: Vec<_> = args.into_iter().collect();
+    if args.iter().any(|arg| arg == "--help") {
+        print_help(day, format);
+        return Ok(());
+    }
+    let mut args = args.into_iter().peekable();
+    let input = Input::for_day(day, args.next_if(|arg| !arg.starts_with("--")))?;
+    let extra_args = extra_args(&input, args.collect())?;
+    run(
+        &*get(day, &extra_args)?,
+        day,
+        &input.read_to_string()?,
+        format,
+    )
+}
+
+/// The extra args from the command line, or if there aren't any,
+/// from the `.args` file next to the input (see [`sidecar_args`]).
+pub fn extra_args(input: &Input, command_line: Vec<String>) -> Result<Vec<String>> {
+    match input {
+        Input::Path(path) if command_line.is_empty() => sidecar_args(path),
+        _ => Ok(command_line),
+    }
+}
+
+/// Say what options a day takes, or in JSON, list its [`OptionSpec`]s.
+pub fn print_help(day: u32, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => println!("day {day}\n\n{}", options::help(options(day))),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string(options(day)).expect("options are always valid JSON")
+        ),
+    }
+}
@@ -0,0 +1,61 @@
+use crate::solution::Solution;
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Parsed = (Vec<i32>, Vec<i32>);
+    type Part1 = u32;
+    type Part2 = i32;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let (mut a, mut b): (Vec<i32>, Vec<i32>) = input
+            .lines()
+            .map(|line| {
+                let mut split = line.split_ascii_whitespace();
+                let a: i32 = split.next().ok_or_else(|| anyhow!("no a"))?.parse()?;
+                let b: i32 = split.next().ok_or_else(|| anyhow!("no b"))?.parse()?;
+                Ok((a, b))
+            })
+            .collect::<Result<_>>()?;
+        a.sort_unstable();
+        b.sort_unstable();
+        Ok((a, b))
+    }
+
+    fn part_1(&self, (a, b): &Self::Parsed) -> Result<u32> {
+        let mut difference_score = 0;
+        for (&a, &b) in a.iter().zip(b) {
+            difference_score += a.abs_diff(b);
+        }
+        Ok(difference_score)
+    }
+
+    fn part_2(&self, (a, b): &Self::Parsed) -> Result<i32> {
+        let mut b_occurrences = BTreeMap::new();
+        for &b in b {
+            b_occurrences.entry(b).and_modify(|x| *x += 1).or_insert(1);
+        }
+        let mut similarity_score = 0;
+        for &a in a {
+            similarity_score += a * b_occurrences.get(&a).copied().unwrap_or(0);
+        }
+        Ok(similarity_score)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/01/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day01.parse(EXAMPLE)?;
+        assert_eq!(Day01.part_1(&parsed)?, 11);
+        assert_eq!(Day01.part_2(&parsed)?, 31);
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use crate::solution::Solution;
+use anyhow::Result;
+
+fn find_error(record: &[i32], skip: Option<usize>) -> Option<usize> {
+    if record.len() < 2 {
+        None
+    } else {
+        let mut has_increased = false;
+        let mut has_decreased = false;
+        let mut prev_level: Option<i32> = None;
+        for (i, &level) in record.iter().enumerate() {
+            if Some(i) != skip {
+                if let Some(prev_level) = prev_level {
+                    if level > prev_level {
+                        has_increased = true;
+                        if level > prev_level + 3 {
+                            return Some(i);
+                        }
+                    } else if level < prev_level {
+                        has_decreased = true;
+                        if level < prev_level - 3 {
+                            return Some(i);
+                        }
+                    } else {
+                        return Some(i);
+                    }
+                    if has_increased && has_decreased {
+                        return Some(i);
+                    }
+                }
+                prev_level = Some(level)
+            }
+        }
+        None
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Parsed = Vec<Vec<i32>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        input
+            .lines()
+            .map(|line| {
+                line.split_ascii_whitespace()
+                    .map(|x| x.parse().map_err(Into::into))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_1(&self, records: &Self::Parsed) -> Result<usize> {
+        Ok(records
+            .iter()
+            .filter(|record| find_error(record, None).is_none())
+            .count())
+    }
+
+    fn part_2(&self, records: &Self::Parsed) -> Result<usize> {
+        let mut safe_dampened_count = 0;
+        for record in records {
+            if let Some(error_index) = find_error(record, None) {
+                for skip in (0..=error_index).rev() {
+                    if find_error(record, Some(skip)).is_none() {
+                        safe_dampened_count += 1;
+                        break;
+                    }
+                }
+            } else {
+                safe_dampened_count += 1;
+            }
+        }
+        Ok(safe_dampened_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/02/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day02.parse(EXAMPLE)?;
+        assert_eq!(Day02.part_1(&parsed)?, 2);
+        assert_eq!(Day02.part_2(&parsed)?, 4);
+        Ok(())
+    }
+}
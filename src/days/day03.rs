@@ -0,0 +1,65 @@
+use crate::solution::Solution;
+use anyhow::Result;
+use regex::Regex;
+
+fn mul_regex() -> Result<Regex> {
+    Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").map_err(Into::into)
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Parsed = String;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<String> {
+        Ok(input.to_owned())
+    }
+
+    fn part_1(&self, input: &String) -> Result<u32> {
+        mul_regex()?
+            .captures_iter(input)
+            .map(|c| {
+                let (_, [a, b]) = c.extract();
+                let a: u32 = a.parse()?;
+                let b: u32 = b.parse()?;
+                Ok(a * b)
+            })
+            .sum()
+    }
+
+    fn part_2(&self, input: &String) -> Result<u32> {
+        let mul_regex = mul_regex()?;
+        let enabled_block_regex = Regex::new(
+            r"(?:^|do\(\))(?:[^d]|d[^o]|do[^n]|don[^']|don'[^t]|don't[^(]|don't\([^)])*",
+        )?;
+
+        enabled_block_regex
+            .find_iter(input)
+            .flat_map(|enabled_block| {
+                mul_regex.captures_iter(enabled_block.as_str()).map(|c| {
+                    let (_, [a, b]) = c.extract();
+                    let a: u32 = a.parse()?;
+                    let b: u32 = b.parse()?;
+                    Ok(a * b)
+                })
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/03/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day03.parse(EXAMPLE)?;
+        assert_eq!(Day03.part_1(&parsed)?, 161);
+        assert_eq!(Day03.part_2(&parsed)?, 48);
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use crate::solution::Solution;
+use anyhow::Result;
+use pest::Parser;
+use pest_derive::Parser;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Parser)]
+#[grammar = "src/days/day05.pest"]
+struct InputParser;
+
+pub struct Rules {
+    prereqs: BTreeMap<u32, BTreeSet<u32>>,
+    updates: Vec<Vec<u32>>,
+}
+
+impl Rules {
+    /// Returns the middle page of each update after putting it in the right order,
+    /// along with whether it needed any correcting.
+    fn corrected_middle_pages(&self) -> impl Iterator<Item = (u32, bool)> + '_ {
+        self.updates.iter().map(|update| {
+            let mut update = update.clone();
+            let mut corrected = false;
+            'outerLoop: loop {
+                let mut seen = BTreeSet::new();
+                let mut unmet_prereqs: BTreeMap<u32, usize> = BTreeMap::new();
+                for (i, &page) in update.iter().enumerate() {
+                    if let Some(&error_i) = unmet_prereqs.get(&page) {
+                        update.copy_within(error_i..i, error_i + 1);
+                        update[error_i] = page;
+                        corrected = true;
+                        continue 'outerLoop;
+                    } else {
+                        seen.insert(page);
+                        if let Some(prereqs) = self.prereqs.get(&page) {
+                            unmet_prereqs.extend(prereqs.difference(&seen).map(|&page| (page, i)));
+                        }
+                    }
+                }
+                break;
+            }
+            (update[update.len() / 2], corrected)
+        })
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Parsed = Rules;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Rules> {
+        let mut file = InputParser::parse(Rule::file, input)?;
+
+        let mut prereqs = BTreeMap::new();
+        for ordering_rule in file
+            .next()
+            .unwrap()
+            .into_inner()
+            .map(|ordering_rule| -> Result<_> {
+                let mut ordering_rule = ordering_rule.into_inner();
+                let needed = ordering_rule.next().unwrap().as_str().parse::<u32>()?;
+                let by = ordering_rule.next().unwrap().as_str().parse::<u32>()?;
+                Ok((needed, by))
+            })
+        {
+            let (needed, by) = ordering_rule?;
+            prereqs
+                .entry(by)
+                .or_insert_with(BTreeSet::new)
+                .insert(needed);
+        }
+
+        let updates = file
+            .next()
+            .unwrap()
+            .into_inner()
+            .map(|update| {
+                update
+                    .into_inner()
+                    .map(|uint| uint.as_str().parse())
+                    .collect::<Result<Vec<u32>, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Rules { prereqs, updates })
+    }
+
+    fn part_1(&self, rules: &Rules) -> Result<u32> {
+        Ok(rules
+            .corrected_middle_pages()
+            .filter_map(|(page, corrected)| (!corrected).then_some(page))
+            .sum())
+    }
+
+    fn part_2(&self, rules: &Rules) -> Result<u32> {
+        Ok(rules
+            .corrected_middle_pages()
+            .filter_map(|(page, corrected)| corrected.then_some(page))
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/05/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day05.parse(EXAMPLE)?;
+        assert_eq!(Day05.part_1(&parsed)?, 143);
+        assert_eq!(Day05.part_2(&parsed)?, 123);
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use crate::{
+    board::{Direction, DirectionSet, Grid},
+    render::{Recorder, Render, Rgb},
+    simulation::Simulation,
+    solution::Solution,
+    tiles,
+};
+use anyhow::Result;
+
+tiles! {
+    pub enum Tile {
+        Open = b'.',
+        Obstruction = b'#',
+        Guard = b'^',
+    }
+}
+
+fn guard_glyph(facing: Direction) -> u8 {
+    use Direction::*;
+    match facing {
+        North => b'^',
+        East => b'>',
+        South => b'v',
+        West => b'<',
+        _ => b'?',
+    }
+}
+
+type Guard = ((usize, usize), Direction);
+
+/// Where the guard is after one more step, or `None` once they've walked off the map.
+/// `extra_obstruction` is for trying out new obstructions in part 2.
+fn guard_step(
+    board: &Grid<Tile>,
+    extra_obstruction: Option<(usize, usize)>,
+    &(position, facing): &Guard,
+) -> Option<Guard> {
+    let new_position = facing.do_move(position)?;
+    match board.get(new_position)? {
+        Tile::Obstruction => Some((position, facing.clockwise())),
+        _ if Some(new_position) == extra_obstruction => Some((position, facing.clockwise())),
+        _ => Some((new_position, facing)),
+    }
+}
+
+/// Which ways the guard faces in each cell they go through,
+/// and whether they end up walking round in circles instead of off the map.
+fn walk(
+    board: &Grid<Tile>,
+    extra_obstruction: Option<(usize, usize)>,
+    start: Guard,
+) -> (Grid<DirectionSet>, bool) {
+    let mut visited = Grid::new(board.width(), board.height(), DirectionSet::EMPTY);
+    // being in the same place facing the same way twice means going round again
+    let looped = Simulation::new(start, |guard| guard_step(board, extra_obstruction, guard))
+        .states()
+        .any(|(position, facing)| !visited[position].insert(facing));
+    (visited, looped)
+}
+
+fn visited_positions(visited: &Grid<DirectionSet>) -> impl Iterator<Item = (usize, usize)> + '_ {
+    visited
+        .iter()
+        .filter(|(_, directions)| !directions.is_empty())
+        .map(|(position, _)| position)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Parsed = (Grid<Tile>, (usize, usize));
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let (mut board, [start_position]) = Grid::parse_with_unique(input, [Tile::Guard])?;
+        board[start_position] = Tile::Open;
+        Ok((board, start_position))
+    }
+
+    fn part_1(&self, &(ref board, start_position): &Self::Parsed) -> Result<usize> {
+        let mut guard = Simulation::new((start_position, Direction::North), |guard| {
+            guard_step(board, None, guard)
+        });
+        let mut visited = Grid::new(board.width(), board.height(), DirectionSet::EMPTY);
+
+        // set AOC_RECORD to watch the guard walk
+        let mut recorder = Recorder::from_env(6, 1)?;
+        for (position, facing) in guard.states() {
+            visited[position].insert(facing);
+            recorder.step(|| {
+                Render::new(board)
+                    .mark(visited_positions(&visited), b'X', Rgb::YELLOW)
+                    .mark([position], guard_glyph(facing), Rgb::RED)
+                    .to_frame()
+            })?;
+        }
+        recorder.finish()?;
+
+        Ok(visited_positions(&visited).count())
+    }
+
+    fn part_2(&self, &(ref board, start_position): &Self::Parsed) -> Result<usize> {
+        let start = (start_position, Direction::North);
+        // an obstruction anywhere the guard doesn't already go wouldn't change anything
+        let (path, _) = walk(board, None, start);
+        Ok(visited_positions(&path)
+            .filter(|&block_position| block_position != start_position)
+            .filter(|&block_position| walk(board, Some(block_position), start).1)
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/06/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day06.parse(EXAMPLE)?;
+        assert_eq!(Day06.part_1(&parsed)?, 41);
+        assert_eq!(Day06.part_2(&parsed)?, 6);
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use crate::solution::Solution;
+use anyhow::Result;
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Add,
+    Mul,
+    Cat,
+}
+
+fn next_ops(ops: &mut [Op]) -> bool {
+    use Op::*;
+    for o in ops {
+        match o {
+            Add => {
+                *o = Mul;
+                return true;
+            }
+            Mul => {
+                *o = Cat;
+                return true;
+            }
+            Cat => {
+                *o = Add;
+            }
+        }
+    }
+    false
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Calibration {
+    Impossible,
+    WithoutCat,
+    WithCat,
+}
+
+fn calibrate(test_value: u64, values: &[u64]) -> Calibration {
+    let mut ops = vec![Op::Add; values.len() - 1];
+    let mut success_with_cat = false;
+    'op_loop: loop {
+        let mut acc = values[0];
+        let mut any_cat = false;
+        for (&x, &op) in values.iter().skip(1).zip(&ops) {
+            acc = match op {
+                Op::Add => acc + x,
+                Op::Mul => acc * x,
+                Op::Cat => {
+                    any_cat = true;
+                    let mut decimal_shift = 10;
+                    while decimal_shift <= x {
+                        decimal_shift *= 10;
+                    }
+                    acc * decimal_shift + x
+                }
+            };
+            if acc > test_value {
+                if !next_ops(&mut ops) {
+                    break 'op_loop;
+                }
+                continue 'op_loop;
+            }
+        }
+
+        if acc == test_value {
+            if any_cat {
+                success_with_cat = true;
+            } else {
+                return Calibration::WithoutCat;
+            }
+        }
+
+        if !next_ops(&mut ops) {
+            break;
+        }
+    }
+
+    if success_with_cat {
+        Calibration::WithCat
+    } else {
+        Calibration::Impossible
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Parsed = Vec<(u64, Vec<u64>)>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        input
+            .lines()
+            .map(|line| {
+                let mut split = line.split(": ");
+                let test_value = split.next().unwrap_or("").parse()?;
+                let values = split
+                    .next()
+                    .unwrap_or("")
+                    .split_ascii_whitespace()
+                    .map(str::parse)
+                    .collect::<Result<_, _>>()?;
+                Ok((test_value, values))
+            })
+            .collect()
+    }
+
+    fn part_1(&self, equations: &Self::Parsed) -> Result<u64> {
+        Ok(equations
+            .iter()
+            .filter(|(test_value, values)| {
+                calibrate(*test_value, values) == Calibration::WithoutCat
+            })
+            .map(|(test_value, _)| test_value)
+            .sum())
+    }
+
+    fn part_2(&self, equations: &Self::Parsed) -> Result<u64> {
+        Ok(equations
+            .iter()
+            .filter(|(test_value, values)| {
+                calibrate(*test_value, values) != Calibration::Impossible
+            })
+            .map(|(test_value, _)| test_value)
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/07/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day07.parse(EXAMPLE)?;
+        assert_eq!(Day07.part_1(&parsed)?, 3749);
+        assert_eq!(Day07.part_2(&parsed)?, 11387);
+        Ok(())
+    }
+}
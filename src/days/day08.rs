@@ -0,0 +1,113 @@
+use crate::{
+    board::{Board, BoardExt, Point, Vector},
+    solution::Solution,
+};
+use anyhow::Result;
+use std::collections::{BTreeMap, BTreeSet};
+
+#[derive(Debug)]
+struct AntinodeIterator<'a> {
+    board: &'a Board,
+    pos: Point,
+    movement: Vector,
+}
+
+impl<'a> AntinodeIterator<'a> {
+    fn new(board: &'a Board, a1: (usize, usize), a2: (usize, usize)) -> Self {
+        let a1 = Point::from(a1);
+        Self {
+            board,
+            pos: a1,
+            movement: a1 - Point::from(a2),
+        }
+    }
+}
+
+impl Iterator for AntinodeIterator<'_> {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next_pos = (self.pos + self.movement).to_usize()?;
+        self.board.get_at(next_pos)?;
+        self.pos = next_pos.into();
+        Some(next_pos)
+    }
+}
+
+struct Antinodes {
+    /// just the ones right next to each pair of antennae
+    first: BTreeSet<(usize, usize)>,
+    /// everything in line with each pair of antennae
+    all: BTreeSet<(usize, usize)>,
+}
+
+fn find_antinodes(board: &Board) -> Antinodes {
+    let mut antennae = BTreeMap::new();
+    for (i, row) in board.iter().enumerate() {
+        for (j, &cell) in row.iter().enumerate() {
+            if cell != b'.' {
+                antennae.entry(cell).or_insert(Vec::new()).push((i, j));
+            }
+        }
+    }
+
+    let mut first_antinodes = BTreeSet::new();
+    let mut all_antinodes = BTreeSet::new();
+    for antennae in antennae.values() {
+        for (i, &a1) in antennae.iter().enumerate() {
+            for &a2 in antennae.iter().skip(i + 1) {
+                all_antinodes.insert(a1);
+                all_antinodes.insert(a2);
+                for (i, antinode) in AntinodeIterator::new(board, a1, a2)
+                    .enumerate()
+                    .chain(AntinodeIterator::new(board, a2, a1).enumerate())
+                {
+                    if i == 0 {
+                        first_antinodes.insert(antinode);
+                    }
+                    all_antinodes.insert(antinode);
+                }
+            }
+        }
+    }
+
+    Antinodes {
+        first: first_antinodes,
+        all: all_antinodes,
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Parsed = Board;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Board> {
+        Board::read(input.as_bytes()).map_err(Into::into)
+    }
+
+    fn part_1(&self, board: &Board) -> Result<usize> {
+        Ok(find_antinodes(board).first.len())
+    }
+
+    fn part_2(&self, board: &Board) -> Result<usize> {
+        Ok(find_antinodes(board).all.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/08/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day08.parse(EXAMPLE)?;
+        assert_eq!(Day08.part_1(&parsed)?, 14);
+        assert_eq!(Day08.part_2(&parsed)?, 34);
+        Ok(())
+    }
+}
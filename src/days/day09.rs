@@ -0,0 +1,123 @@
+use crate::solution::Solution;
+use anyhow::Result;
+
+fn filesystem_checksum(disk: &[Option<u64>]) -> u64 {
+    disk.iter()
+        .enumerate()
+        .filter_map(|(i, block)| block.map(|file_id| (i as u64) * file_id))
+        .sum()
+}
+
+#[derive(Debug, Clone)]
+struct Chunk {
+    index: usize,
+    len: usize,
+}
+
+pub struct Disk {
+    blocks: Vec<Option<u64>>,
+    file_block_indices: Vec<usize>,
+    free_block_indices: Vec<usize>,
+    file_chunks: Vec<Chunk>,
+    free_chunks: Vec<Chunk>,
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Parsed = Disk;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Disk> {
+        let mut disk = Disk {
+            blocks: Vec::new(),
+            file_block_indices: Vec::new(),
+            free_block_indices: Vec::new(),
+            file_chunks: Vec::new(),
+            free_chunks: Vec::new(),
+        };
+
+        let mut next_file_id = 0;
+        for (b, is_file) in input.bytes().zip([true, false].into_iter().cycle()) {
+            if b.is_ascii_digit() {
+                let block_count = b - b'0';
+                if is_file {
+                    let file_id = next_file_id;
+                    next_file_id += 1;
+                    disk.file_chunks.push(Chunk {
+                        index: disk.blocks.len(),
+                        len: block_count.into(),
+                    });
+                    for _ in 0..block_count {
+                        disk.file_block_indices.push(disk.blocks.len());
+                        disk.blocks.push(Some(file_id));
+                    }
+                } else {
+                    disk.free_chunks.push(Chunk {
+                        index: disk.blocks.len(),
+                        len: block_count.into(),
+                    });
+                    for _ in 0..block_count {
+                        disk.free_block_indices.push(disk.blocks.len());
+                        disk.blocks.push(None);
+                    }
+                }
+            }
+        }
+
+        Ok(disk)
+    }
+
+    fn part_1(&self, disk: &Disk) -> Result<u64> {
+        let mut disk_1 = disk.blocks.clone();
+        for (&file_index, &free_index) in disk
+            .file_block_indices
+            .iter()
+            .rev()
+            .zip(&disk.free_block_indices)
+        {
+            if free_index > file_index {
+                break;
+            }
+            let file_id = disk_1[file_index].take().unwrap();
+            _ = disk_1[free_index].insert(file_id);
+        }
+
+        Ok(filesystem_checksum(&disk_1))
+    }
+
+    fn part_2(&self, disk: &Disk) -> Result<u64> {
+        let mut disk_2 = disk.blocks.clone();
+        let mut free_chunks = disk.free_chunks.clone();
+        for file_chunk in disk.file_chunks.iter().rev() {
+            for free_chunk in free_chunks.iter_mut() {
+                if free_chunk.index < file_chunk.index && free_chunk.len >= file_chunk.len {
+                    let (a, b) = disk_2.split_at_mut(file_chunk.index);
+                    a[free_chunk.index..(free_chunk.index + file_chunk.len)]
+                        .swap_with_slice(&mut b[..file_chunk.len]);
+                    free_chunk.len -= file_chunk.len;
+                    free_chunk.index += file_chunk.len;
+                    break;
+                }
+            }
+        }
+
+        Ok(filesystem_checksum(&disk_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/09/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day09.parse(EXAMPLE)?;
+        assert_eq!(Day09.part_1(&parsed)?, 1928);
+        assert_eq!(Day09.part_2(&parsed)?, 2858);
+        Ok(())
+    }
+}
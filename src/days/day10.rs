@@ -0,0 +1,58 @@
+use crate::{
+    board::{Grid, GridGraph},
+    graph::count_paths_to_all,
+    solution::Solution,
+};
+use anyhow::Result;
+use std::collections::HashMap;
+
+/// The number of distinct trails between each trailhead and each summit it can reach.
+fn trail_counts(map: &Grid<u8>) -> Result<Vec<usize>> {
+    let trails = GridGraph::from_grid(map, |_| true).edges(|from, to| map[to] == map[from] + 1);
+
+    let mut trail_counts = Vec::new();
+    for (trailhead, _) in map.iter().filter(|&(_, &height)| height == b'0') {
+        let summit_counts: HashMap<_, usize> = count_paths_to_all(
+            trailhead,
+            |&pos| trails.neighbors(pos),
+            |&pos| map[pos] == b'9',
+        )?;
+        trail_counts.extend(summit_counts.into_values());
+    }
+    Ok(trail_counts)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Grid<u8>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Grid<u8>> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part_1(&self, map: &Grid<u8>) -> Result<usize> {
+        Ok(trail_counts(map)?.len())
+    }
+
+    fn part_2(&self, map: &Grid<u8>) -> Result<usize> {
+        Ok(trail_counts(map)?.into_iter().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/10/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day10.parse(EXAMPLE)?;
+        assert_eq!(Day10.part_1(&parsed)?, 36);
+        assert_eq!(Day10.part_2(&parsed)?, 81);
+        Ok(())
+    }
+}
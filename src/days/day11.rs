@@ -0,0 +1,94 @@
+use crate::solution::Solution;
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+fn split_digits(value: u64) -> Option<(u64, u64)> {
+    let mut mid = 1;
+    let mut top = 10;
+    loop {
+        if value < top {
+            return None;
+        }
+        top *= 10;
+        mid *= 10;
+        if value < top {
+            return Some((value / mid, value % mid));
+        }
+        top *= 10;
+    }
+}
+
+struct MemoizedSuccessors(BTreeMap<(u64, u32), u64>);
+
+impl MemoizedSuccessors {
+    fn new() -> MemoizedSuccessors {
+        MemoizedSuccessors(BTreeMap::new())
+    }
+
+    fn successors_count_after(&mut self, value: u64, blinks: u32) -> u64 {
+        if blinks == 0 {
+            1
+        } else if let Some(&count) = self.0.get(&(value, blinks)) {
+            count
+        } else {
+            let count = if value == 0 {
+                self.successors_count_after(1, blinks - 1)
+            } else if let Some((a, b)) = split_digits(value) {
+                self.successors_count_after(a, blinks - 1)
+                    + self.successors_count_after(b, blinks - 1)
+            } else {
+                self.successors_count_after(value * 2024, blinks - 1)
+            };
+            self.0.insert((value, blinks), count);
+            count
+        }
+    }
+}
+
+fn total_after(initial_stones: &[u64], blinks: u32) -> u64 {
+    let mut table = MemoizedSuccessors::new();
+    initial_stones
+        .iter()
+        .map(|&value| table.successors_count_after(value, blinks))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<u64>> {
+        input
+            .split_ascii_whitespace()
+            .map(str::parse)
+            .collect::<Result<_, _>>()
+            .map_err(Into::into)
+    }
+
+    fn part_1(&self, initial_stones: &Vec<u64>) -> Result<u64> {
+        Ok(total_after(initial_stones, 25))
+    }
+
+    fn part_2(&self, initial_stones: &Vec<u64>) -> Result<u64> {
+        Ok(total_after(initial_stones, 75))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/11/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day11.parse(EXAMPLE)?;
+        assert_eq!(Day11.part_1(&parsed)?, 55312);
+        // the puzzle doesn't give an answer for this, so this is just what the solution that got the star says
+        assert_eq!(Day11.part_2(&parsed)?, 65601038650482);
+        Ok(())
+    }
+}
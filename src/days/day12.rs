@@ -0,0 +1,78 @@
+use crate::{
+    board::{Adjacency, Grid, Regions},
+    solution::Solution,
+};
+use anyhow::Result;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed = Regions;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Regions> {
+        let garden: Grid<u8> = Grid::parse(input)?;
+        Ok(garden.regions(Adjacency::Orthogonal))
+    }
+
+    fn part_1(&self, regions: &Regions) -> Result<usize> {
+        Ok(regions
+            .iter()
+            .map(|region| region.area * region.perimeter)
+            .sum())
+    }
+
+    fn part_2(&self, regions: &Regions) -> Result<usize> {
+        Ok(regions
+            .iter()
+            .map(|region| region.area * region.sides)
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/12/example.txt");
+
+    const E_SHAPED_EXAMPLE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+    const NESTED_EXAMPLE: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day12.parse(EXAMPLE)?;
+        assert_eq!(Day12.part_1(&parsed)?, 1930);
+        assert_eq!(Day12.part_2(&parsed)?, 1206);
+        Ok(())
+    }
+
+    #[test]
+    fn e_shaped_example() -> Result<()> {
+        let parsed = Day12.parse(E_SHAPED_EXAMPLE)?;
+        assert_eq!(Day12.part_2(&parsed)?, 236);
+        Ok(())
+    }
+
+    #[test]
+    fn nested_example() -> Result<()> {
+        let parsed = Day12.parse(NESTED_EXAMPLE)?;
+        assert_eq!(Day12.part_2(&parsed)?, 368);
+        Ok(())
+    }
+}
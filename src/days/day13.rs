@@ -0,0 +1,109 @@
+use crate::solution::Solution;
+use anyhow::Result;
+use pest::Parser;
+use pest_derive::Parser;
+
+#[derive(Parser)]
+#[grammar = "src/days/day13.pest"]
+struct InputParser;
+
+#[derive(Debug, Clone)]
+pub struct Machine {
+    a: (i64, i64),
+    b: (i64, i64),
+    prize: (i64, i64),
+}
+
+fn checked_exact_div(dividend: i64, divisor: i64) -> Option<i64> {
+    if divisor == 0 || dividend % divisor != 0 {
+        None
+    } else {
+        dividend.checked_div(divisor)
+    }
+}
+
+impl Machine {
+    #[allow(
+        non_snake_case,
+        reason = "I'm doing algebra here, I need short and similar variable names"
+    )]
+    fn cost(&self) -> Option<i64> {
+        let &Machine { a, b, prize: p } = self;
+        // p.0 == A*a.0 + B*b.0
+        // p.1 == A*a.1 + B*b.1
+        //
+        // uhh I definitely remember how to solve systems of linear equations
+        //
+        // p.0*b.1 == A*a.0*b.1 + B*b.0*b.1
+        // p.1*b.0 == A*a.1*b.0 + B*b.1*b.0
+        //
+        // p.0*b.1 - p.1*b.0 == A*a.0*b.1 - A*a.1*b.0
+        // p.0*b.1 - p.1*b.0 == A*(a.0*b.1 - a.1*b.0)
+        // A == (p.0*b.1 - p.1*b.0) / (a.0*b.1 - a.1*b.0)
+        let A = checked_exact_div(p.0 * b.1 - p.1 * b.0, a.0 * b.1 - a.1 * b.0)?;
+        // B == (p.0 - A*a.0) / b.0
+        let B = checked_exact_div(p.0 - A * a.0, b.0)?;
+        Some(3 * A + B)
+    }
+
+    fn adjust_for_part_2(&mut self) {
+        self.prize.0 += 10000000000000;
+        self.prize.1 += 10000000000000;
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed = Vec<Machine>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Machine>> {
+        InputParser::parse(Rule::file, input)?
+            .filter(|machine| machine.as_rule() == Rule::machine)
+            .map(|machine| {
+                let mut machine = machine.into_inner();
+                let a = (
+                    machine.next().unwrap().as_str().parse()?,
+                    machine.next().unwrap().as_str().parse()?,
+                );
+                let b = (
+                    machine.next().unwrap().as_str().parse()?,
+                    machine.next().unwrap().as_str().parse()?,
+                );
+                let prize = (
+                    machine.next().unwrap().as_str().parse()?,
+                    machine.next().unwrap().as_str().parse()?,
+                );
+                Ok(Machine { a, b, prize })
+            })
+            .collect()
+    }
+
+    fn part_1(&self, machines: &Vec<Machine>) -> Result<i64> {
+        Ok(machines.iter().filter_map(Machine::cost).sum())
+    }
+
+    fn part_2(&self, machines: &Vec<Machine>) -> Result<i64> {
+        let mut machines = machines.clone();
+        machines.iter_mut().for_each(Machine::adjust_for_part_2);
+        Ok(machines.iter().filter_map(Machine::cost).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/13/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day13.parse(EXAMPLE)?;
+        assert_eq!(Day13.part_1(&parsed)?, 480);
+        // the puzzle doesn't give an answer for this, so this is just what the solution that got the star says
+        assert_eq!(Day13.part_2(&parsed)?, 875318608908);
+        Ok(())
+    }
+}
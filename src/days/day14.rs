@@ -0,0 +1,228 @@
+use crate::{
+    board::{Grid, Point, Topology, Vector},
+    options::{OptionSpec, Options},
+    render::{Recorder, Render, Rgb},
+    simulation::{Cycle, Simulation},
+    solution::Solution,
+};
+use anyhow::{anyhow, Result};
+use pest::{iterators::Pair, Parser};
+use pest_derive::Parser;
+
+#[derive(Parser)]
+#[grammar = "src/days/day14.pest"]
+struct InputParser;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Robot {
+    position: Point,
+    velocity: Vector,
+}
+
+impl Robot {
+    fn position_after(&self, seconds: i64, room: Topology) -> Point {
+        room.resolve(self.position + self.velocity * seconds)
+            .expect("the room wraps around, so nothing is off the edge")
+    }
+
+    fn step(&mut self, room: Topology) {
+        self.position = self.position_after(1, room);
+    }
+}
+
+/// The input is `x,y`, so this comes out as `(i, j) = (y, x)`.
+fn parse_int_pair(pair: Pair<'_, Rule>) -> Result<(i64, i64)> {
+    let mut pairs = pair.into_inner();
+    let x = pairs.next().unwrap().as_str().parse()?;
+    let y = pairs.next().unwrap().as_str().parse()?;
+    Ok((y, x))
+}
+
+pub struct Day14 {
+    room_width: i64,
+    room_height: i64,
+}
+
+impl Day14 {
+    pub fn new(room_width: i64, room_height: i64) -> Day14 {
+        Day14 {
+            room_width,
+            room_height,
+        }
+    }
+
+    /// The room is 101 × 103 in the real puzzle, but only 11 × 7 in the example.
+    pub const OPTIONS: &'static [OptionSpec] = &[
+        OptionSpec {
+            name: "width",
+            value: "N",
+            help: "how wide the room is",
+            default: Some("101"),
+        },
+        OptionSpec {
+            name: "height",
+            value: "N",
+            help: "how tall the room is",
+            default: Some("103"),
+        },
+    ];
+
+    pub fn from_options(options: &Options) -> Result<Day14> {
+        Ok(Day14::new(options.get("width")?, options.get("height")?))
+    }
+
+    fn room(&self) -> Result<Topology> {
+        if self.room_width <= 0 || self.room_height <= 0 {
+            return Err(anyhow!("the room has to be at least 1x1"));
+        }
+        Ok(Topology::Wrapping {
+            width: self.room_width.try_into()?,
+            height: self.room_height.try_into()?,
+        })
+    }
+}
+
+impl Solution for Day14 {
+    type Parsed = Vec<Robot>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Vec<Robot>> {
+        InputParser::parse(Rule::file, input)?
+            .filter(|robot| robot.as_rule() == Rule::robot)
+            .map(|robot| {
+                let mut robot = robot.into_inner();
+                let (i, j) = parse_int_pair(robot.next().unwrap())?;
+                let position = Point::new(i, j);
+                let (i, j) = parse_int_pair(robot.next().unwrap())?;
+                let velocity = Vector::new(i, j);
+                Ok(Robot { position, velocity })
+            })
+            .collect()
+    }
+
+    fn part_1(&self, robots: &Vec<Robot>) -> Result<usize> {
+        let &Day14 {
+            room_width,
+            room_height,
+        } = self;
+
+        let room = self.room()?;
+        let (middle_x, middle_y) = (room_width / 2, room_height / 2);
+        let mut quadrant_counts = [0; 4];
+        for r in robots {
+            let Point { i: y, j: x } = r.position_after(100, room);
+            if x != middle_x && y != middle_y {
+                quadrant_counts[usize::from(x > middle_x) + 2 * usize::from(y > middle_y)] += 1;
+            }
+        }
+        Ok(quadrant_counts.into_iter().product())
+    }
+
+    // this was the first part I had to look up a solution for...
+    // I just couldn't believe that what they wanted us to do was actually look through each picture individually
+    // so I found https://www.youtube.com/watch?v=Zyvd-MWo7uE
+    // and indeed, that seems to be the intended solution :/
+    // I ended up finding it by Ctrl-F-ing for a bunch of asterisks in a row, so that's what this does
+
+    fn part_2(&self, robots: &Vec<Robot>) -> Result<usize> {
+        let &Day14 {
+            room_width,
+            room_height,
+        } = self;
+        let room = self.room()?;
+        let floor = Grid::new(room_width.try_into()?, room_height.try_into()?, b'.');
+        let mut simulation = Simulation::new(robots.clone(), |robots: &Vec<Robot>| {
+            let mut robots = robots.clone();
+            for r in &mut robots {
+                r.step(room);
+            }
+            Some(robots)
+        });
+        let Cycle { tail, period } = simulation
+            .cycle()
+            .expect("the robots never stop, so they have to go round in circles");
+
+        // set AOC_RECORD to look through them all as an animation
+        let mut recorder = Recorder::from_env(14, 2)?;
+        let mut best: Option<(usize, usize)> = None;
+        for (step_count, robots) in simulation.states().take(tail + period).enumerate() {
+            recorder.step(|| {
+                Render::new(&floor)
+                    .mark(
+                        robots.iter().filter_map(|r| r.position.to_usize()),
+                        b'*',
+                        Rgb::GREEN,
+                    )
+                    .to_frame()
+            })?;
+
+            let run = longest_run(&robots);
+            if best.is_none_or(|(best_run, _)| run > best_run) {
+                best = Some((run, step_count));
+            }
+        }
+        recorder.finish()?;
+
+        let (_, step_count) = best.expect("there's always at least the first step");
+        Ok(step_count)
+    }
+}
+
+/// The most robots standing next to each other in the same row.
+fn longest_run(robots: &[Robot]) -> usize {
+    let mut positions: Vec<_> = robots
+        .iter()
+        .map(|r| (r.position.i, r.position.j))
+        .collect();
+    positions.sort_unstable();
+    positions.dedup();
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous = None;
+    for (i, j) in positions {
+        run = if previous == Some((i, j - 1)) {
+            run + 1
+        } else {
+            1
+        };
+        longest = longest.max(run);
+        previous = Some((i, j));
+    }
+    longest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/14/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let day = Day14::new(11, 7);
+        let parsed = day.parse(EXAMPLE)?;
+        assert_eq!(day.part_1(&parsed)?, 12);
+        // there's no tree in the example, but the robots come back round after 11 × 7 steps
+        assert!(day.part_2(&parsed)? < 77);
+        Ok(())
+    }
+
+    #[test]
+    fn finds_robots_in_a_row() -> Result<()> {
+        // the example robots, plus seven more that all end up side by side
+        // in the middle row after 23 steps, each coming from a different direction
+        let day = Day14::new(11, 7);
+        let mut robots = day.parse(EXAMPLE)?;
+        for k in 0..7 {
+            let velocity = Vector::new(k % 3 - 1, k - 3);
+            let position = Point::new(3, 2 + k) + velocity * -23;
+            robots.push(Robot {
+                position: day.room()?.resolve(position).unwrap(),
+                velocity,
+            });
+        }
+        assert_eq!(day.part_2(&robots)?, 23);
+        Ok(())
+    }
+}
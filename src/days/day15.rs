@@ -0,0 +1,244 @@
+use crate::{
+    board::{CellSet, Direction, Grid, Point},
+    render::{Frame, Recorder, Render, Rgb},
+    solution::Solution,
+    tiles,
+};
+use anyhow::Result;
+use pest::Parser;
+use pest_derive::Parser;
+use std::collections::BTreeSet;
+
+#[derive(Parser)]
+#[grammar = "src/days/day15.pest"]
+struct InputParser;
+
+tiles! {
+    pub enum Tile {
+        Open = b'.',
+        Wall = b'#',
+        Box = b'O',
+        Robot = b'@',
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = (Grid<Tile>, (usize, usize), Vec<Direction>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let mut parsed = InputParser::parse(Rule::file, input)?;
+
+        let (board, [robot_position]) =
+            Grid::parse_with_unique(parsed.next().unwrap().as_str().trim_end(), [Tile::Robot])?;
+        let moves: Vec<_> = parsed
+            .next()
+            .unwrap()
+            .into_inner()
+            .map(|movement| match movement.as_rule() {
+                Rule::move_up => Direction::North,
+                Rule::move_down => Direction::South,
+                Rule::move_left => Direction::West,
+                Rule::move_right => Direction::East,
+                _ => unreachable!(),
+            })
+            .collect();
+        Ok((board, robot_position, moves))
+    }
+
+    fn part_1(&self, (board, robot_position, moves): &Self::Parsed) -> Result<usize> {
+        let mut board = board.clone();
+        let mut robot_position = *robot_position;
+
+        fn try_move(
+            board: &mut Grid<Tile>,
+            position: (usize, usize),
+            direction: Direction,
+        ) -> bool {
+            if let Some(new_position) = direction.do_move(position) {
+                match board.get(new_position).copied() {
+                    Some(Tile::Open) => {
+                        board[new_position] = board[position];
+                        board[position] = Tile::Open;
+                        true
+                    }
+                    Some(Tile::Box) if try_move(board, new_position, direction) => {
+                        board[new_position] = board[position];
+                        board[position] = Tile::Open;
+                        true
+                    }
+                    _ => false,
+                }
+            } else {
+                false
+            }
+        }
+
+        for &direction in moves {
+            if try_move(&mut board, robot_position, direction) {
+                robot_position = direction
+                    .do_move(robot_position)
+                    .expect("try_move only returns true if this is Some")
+            }
+        }
+
+        let gps_sum = board
+            .iter()
+            .filter_map(|((i, j), &cell)| {
+                if cell == Tile::Box {
+                    Some(100 * i + j)
+                } else {
+                    None
+                }
+            })
+            .sum::<usize>();
+        Ok(gps_sum)
+    }
+
+    fn part_2(&self, (board, robot_position, moves): &Self::Parsed) -> Result<usize> {
+        let mut walls = CellSet::new();
+        let mut boxes = CellSet::new();
+        for ((i, j), &cell) in board.iter() {
+            // everything's twice as wide, and boxes are where their left half is
+            let left = Point::from((i, 2 * j));
+            match cell {
+                Tile::Wall => {
+                    walls.extend([left, left + Direction::East]);
+                }
+                Tile::Box => {
+                    boxes.add(left);
+                }
+                Tile::Open | Tile::Robot => {}
+            }
+        }
+        let walls = walls;
+        let mut robot_position = Point::from((robot_position.0, 2 * robot_position.1));
+
+        fn draw(
+            height: usize,
+            width: usize,
+            walls: &CellSet,
+            boxes: &CellSet,
+            robot_position: Point,
+        ) -> Frame {
+            let glyph = |pos| {
+                let pos = Point::from(pos);
+                if walls.contains(pos) {
+                    b'#'
+                } else if boxes.contains(pos) {
+                    b'['
+                } else if boxes.contains(pos + Direction::West) {
+                    b']'
+                } else {
+                    b'.'
+                }
+            };
+            Render::new(&Grid::from_fn(width, height, glyph))
+                .mark(robot_position.to_usize(), b'@', Rgb::RED)
+                .to_frame()
+        }
+
+        // set AOC_RECORD to watch the robot push boxes around
+        let mut recorder = Recorder::from_env(15, 2)?;
+        'moveLoop: for &direction in moves {
+            recorder.step(|| {
+                draw(
+                    board.height(),
+                    board.width() * 2,
+                    &walls,
+                    &boxes,
+                    robot_position,
+                )
+            })?;
+            let new_robot_position = robot_position + direction;
+            if walls.contains(new_robot_position) {
+                continue;
+            }
+
+            let Point { i, j } = new_robot_position;
+            let mut unchecked_boxes: BTreeSet<_> =
+                boxes.row_range(i, j - 1..=j).map(|(pos, _)| pos).collect();
+            let mut checked_boxes = BTreeSet::new();
+            while let Some(box_position) = unchecked_boxes.pop_first() {
+                checked_boxes.insert(box_position);
+                let Point { i, j } = box_position + direction;
+                if walls.row_range(i, j..=j + 1).next().is_some() {
+                    continue 'moveLoop;
+                }
+                unchecked_boxes.extend(
+                    boxes
+                        .row_range(i, j - 1..=j + 1)
+                        .map(|(pos, _)| pos)
+                        .filter(|box_position| !checked_boxes.contains(box_position)),
+                );
+            }
+
+            // checked_boxes is full of all the boxes that need to move and they all can
+            for &box_position in &checked_boxes {
+                boxes.remove(box_position);
+            }
+            boxes.extend(
+                checked_boxes
+                    .into_iter()
+                    .map(|box_position| box_position + direction),
+            );
+
+            robot_position = new_robot_position;
+        }
+
+        recorder.step(|| {
+            draw(
+                board.height(),
+                board.width() * 2,
+                &walls,
+                &boxes,
+                robot_position,
+            )
+        })?;
+        recorder.finish()?;
+
+        let gps_sum = boxes
+            .positions()
+            .map(|Point { i, j }| 100 * i + j)
+            .sum::<i64>();
+        Ok(gps_sum.try_into()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/15/example.txt");
+
+    const SMALLER_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day15.parse(EXAMPLE)?;
+        assert_eq!(Day15.part_1(&parsed)?, 10092);
+        assert_eq!(Day15.part_2(&parsed)?, 9021);
+        Ok(())
+    }
+
+    #[test]
+    fn smaller_example() -> Result<()> {
+        let parsed = Day15.parse(SMALLER_EXAMPLE)?;
+        assert_eq!(Day15.part_1(&parsed)?, 2028);
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    board::{Direction, Grid, GridGraph, Orientation},
+    graph::{shortest_paths, ShortestPaths},
+    solution::Solution,
+    tiles,
+};
+use anyhow::{anyhow, Result};
+use std::collections::BTreeSet;
+
+tiles! {
+    pub enum Tile {
+        Open = b'.',
+        Wall = b'#',
+        Start = b'S',
+        End = b'E',
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    /// Every best path through the maze, found once for both parts.
+    type Parsed = ShortestPaths<Orientation, usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        let (board, [start_pos, end_pos]) =
+            Grid::parse_with_unique(input, [Tile::Start, Tile::End])?;
+
+        let maze = GridGraph::from_grid(&board, |&tile| tile != Tile::Wall);
+        shortest_paths(
+            &Orientation {
+                position: start_pos,
+                direction: Direction::East,
+            },
+            |&orientation| maze.oriented_successors(orientation, 1000),
+            |&orientation| maze.oriented_predecessors(orientation, 1000),
+            |&Orientation { position, .. }| position == end_pos,
+        )
+        .ok_or_else(|| anyhow!("no path"))
+    }
+
+    fn part_1(&self, best_paths: &Self::Parsed) -> Result<usize> {
+        Ok(best_paths.cost())
+    }
+
+    fn part_2(&self, best_paths: &Self::Parsed) -> Result<usize> {
+        Ok(best_paths
+            .nodes()
+            .map(|&Orientation { position, .. }| position)
+            .collect::<BTreeSet<_>>()
+            .len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/16/example.txt");
+
+    const SECOND_EXAMPLE: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day16.parse(EXAMPLE)?;
+        assert_eq!(Day16.part_1(&parsed)?, 7036);
+        assert_eq!(Day16.part_2(&parsed)?, 45);
+        Ok(())
+    }
+
+    #[test]
+    fn second_example() -> Result<()> {
+        let parsed = Day16.parse(SECOND_EXAMPLE)?;
+        assert_eq!(Day16.part_1(&parsed)?, 11048);
+        assert_eq!(Day16.part_2(&parsed)?, 64);
+        Ok(())
+    }
+}
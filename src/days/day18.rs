@@ -0,0 +1,122 @@
+use crate::{
+    board::{Blocker, CellSet, Connectivity, GridGraph, Point, Topology},
+    options::{OptionSpec, Options},
+    solution::Solution,
+};
+use anyhow::{anyhow, Result};
+use pathfinding::prelude::astar;
+
+fn find_path(
+    mem_side_length: usize,
+    fallen_bytes: &CellSet,
+) -> Option<(Vec<(usize, usize)>, usize)> {
+    let memory = Topology::Bounded {
+        width: mem_side_length + 1,
+        height: mem_side_length + 1,
+    };
+    let end = (mem_side_length, mem_side_length);
+    let graph = GridGraph::new(memory, |pos| !fallen_bytes.contains(pos.into()));
+    astar(
+        &(0, 0),
+        |&pos| graph.successors(pos),
+        |&pos| memory.manhattan_distance(pos.into(), end.into()) as usize,
+        |&pos| pos == end,
+    )
+}
+
+pub struct Day18 {
+    mem_side_length: usize,
+    part_1_count: usize,
+}
+
+impl Day18 {
+    pub fn new(mem_side_length: usize, part_1_count: usize) -> Day18 {
+        Day18 {
+            mem_side_length,
+            part_1_count,
+        }
+    }
+
+    /// The real puzzle goes up to 70 and uses 1024 bytes, but the example only goes up to 6 and uses 12.
+    pub const OPTIONS: &'static [OptionSpec] = &[
+        OptionSpec {
+            name: "size",
+            value: "N",
+            help: "the biggest coordinate in memory",
+            default: Some("70"),
+        },
+        OptionSpec {
+            name: "bytes",
+            value: "N",
+            help: "how many bytes have fallen for part 1",
+            default: Some("1024"),
+        },
+    ];
+
+    pub fn from_options(options: &Options) -> Result<Day18> {
+        Ok(Day18::new(options.get("size")?, options.get("bytes")?))
+    }
+}
+
+impl Solution for Day18 {
+    type Parsed = Vec<(usize, usize)>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed> {
+        input
+            .lines()
+            .map(|line| {
+                let mut coords = line.split(",").map(str::parse);
+                Ok((
+                    coords.next().ok_or_else(|| anyhow!("no x"))??,
+                    coords.next().ok_or_else(|| anyhow!("no y"))??,
+                ))
+            })
+            .collect()
+    }
+
+    fn part_1(&self, incoming_bytes: &Self::Parsed) -> Result<usize> {
+        let fallen_bytes: CellSet = incoming_bytes
+            .iter()
+            .map(|&pos| Point::from(pos))
+            .take(self.part_1_count)
+            .collect();
+
+        let (_, cost) = find_path(self.mem_side_length, &fallen_bytes)
+            .ok_or_else(|| anyhow!("no path in part 1"))?;
+        Ok(cost)
+    }
+
+    fn part_2(&self, incoming_bytes: &Self::Parsed) -> Result<String> {
+        let memory = Topology::Bounded {
+            width: self.mem_side_length + 1,
+            height: self.mem_side_length + 1,
+        };
+        let end = Point::new(self.mem_side_length as i64, self.mem_side_length as i64);
+        let Blocker { position, .. } = Connectivity::new(memory)
+            .first_blocker(
+                Point::ORIGIN,
+                end,
+                incoming_bytes.iter().map(|&pos| Point::from(pos)),
+            )
+            .ok_or_else(|| anyhow!("never blocked in part 2"))?;
+        Ok(format!("{},{}", position.i, position.j))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/18/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let day = Day18::new(6, 12);
+        let parsed = day.parse(EXAMPLE)?;
+        assert_eq!(day.part_1(&parsed)?, 22);
+        assert_eq!(day.part_2(&parsed)?, "6,1");
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use crate::{graph::count_paths, solution::Solution};
+use anyhow::Result;
+use pest::Parser;
+use pest_derive::Parser;
+
+#[derive(Parser)]
+#[grammar = "src/days/day19.pest"]
+struct InputParser;
+
+pub struct Onsen {
+    towels: Vec<String>,
+    patterns: Vec<String>,
+}
+
+impl Onsen {
+    fn arrangement_counts(&self) -> Result<Vec<u64>> {
+        self.patterns
+            .iter()
+            .map(|pattern| {
+                let count = count_paths(
+                    String::new(),
+                    |at| {
+                        self.towels
+                            .iter()
+                            .map(|towel| at.clone() + towel)
+                            .filter(|next| pattern.starts_with(next))
+                            .collect::<Vec<_>>()
+                    },
+                    |at| at == pattern,
+                )?;
+                Ok(count)
+            })
+            .collect()
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed = Onsen;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Onsen> {
+        let mut file = InputParser::parse(Rule::file, input)?;
+        let towels = file
+            .next()
+            .unwrap()
+            .into_inner()
+            .map(|towel| towel.as_str().to_owned())
+            .collect();
+        let patterns = file
+            .next()
+            .unwrap()
+            .into_inner()
+            .map(|pattern| pattern.as_str().to_owned())
+            .collect();
+        Ok(Onsen { towels, patterns })
+    }
+
+    fn part_1(&self, onsen: &Onsen) -> Result<usize> {
+        Ok(onsen
+            .arrangement_counts()?
+            .into_iter()
+            .filter(|&arrangement_count| arrangement_count > 0)
+            .count())
+    }
+
+    fn part_2(&self, onsen: &Onsen) -> Result<u64> {
+        Ok(onsen.arrangement_counts()?.into_iter().sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/19/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day19.parse(EXAMPLE)?;
+        assert_eq!(Day19.part_1(&parsed)?, 6);
+        assert_eq!(Day19.part_2(&parsed)?, 16);
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    board::{shortcut_savings, Grid, GridGraph},
+    solution::Solution,
+    tiles,
+};
+use anyhow::Result;
+use std::collections::BTreeMap;
+
+tiles! {
+    pub enum Tile {
+        Open = b'.',
+        Wall = b'#',
+        Start = b'S',
+        End = b'E',
+    }
+}
+
+pub struct Racetrack {
+    costs_from_start: Grid<Option<usize>>,
+    costs_to_end: Grid<Option<usize>>,
+}
+
+impl Racetrack {
+    /// How many cheats of up to `cheat_duration` steps save each amount of time.
+    pub fn savings(&self, cheat_duration: usize) -> BTreeMap<usize, usize> {
+        shortcut_savings(
+            &self.costs_from_start,
+            &self.costs_to_end,
+            cheat_duration,
+            1,
+        )
+    }
+
+    /// How many cheats save at least 100 picoseconds.
+    fn cheats_saving_100(&self, cheat_duration: usize) -> usize {
+        self.savings(cheat_duration)
+            .range(100..)
+            .map(|(_, count)| count)
+            .sum()
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Racetrack;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Racetrack> {
+        let (board, [start, end]) = Grid::parse_with_unique(input, [Tile::Start, Tile::End])?;
+
+        let track = GridGraph::from_grid(&board, |&tile| tile != Tile::Wall);
+        Ok(Racetrack {
+            costs_from_start: track.distances(start),
+            costs_to_end: track.distances(end),
+        })
+    }
+
+    fn part_1(&self, racetrack: &Racetrack) -> Result<usize> {
+        Ok(racetrack.cheats_saving_100(2))
+    }
+
+    fn part_2(&self, racetrack: &Racetrack) -> Result<usize> {
+        Ok(racetrack.cheats_saving_100(20))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../bin/20/example.txt");
+
+    #[test]
+    fn example() -> Result<()> {
+        let parsed = Day20.parse(EXAMPLE)?;
+        // no cheats in the example save 100 picoseconds
+        assert_eq!(Day20.part_1(&parsed)?, 0);
+        assert_eq!(Day20.part_2(&parsed)?, 0);
+        let savings = parsed.savings(2);
+        assert_eq!(savings[&2], 14);
+        assert_eq!(savings[&64], 1);
+        let savings = parsed.savings(20);
+        assert_eq!(savings[&50], 32);
+        assert_eq!(savings[&76], 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,351 @@
+//! Graph algorithms that `pathfinding` doesn't quite have.
+
+use num_traits::{One, Zero};
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap},
+    hash::Hash,
+};
+
+mod count_paths;
+
+pub use count_paths::{
+    count_paths, count_paths_to_all, count_paths_to_all_with_hasher, count_paths_with_hasher,
+    CycleError,
+};
+
+/// Every shortest path from a start node to the nearest goals, kept as a DAG
+/// rather than a list of paths, since there can be exponentially many of those.
+#[derive(Debug, Clone)]
+pub struct ShortestPaths<N, C> {
+    cost: C,
+    goals: Vec<N>,
+    /// Nodes on some shortest path, ordered by their distance from the start.
+    nodes: Vec<N>,
+    /// The previous node on each shortest path to a node, for every node on one.
+    parents: HashMap<N, Vec<N>>,
+    from_start: HashMap<N, C>,
+    to_goal: HashMap<N, C>,
+}
+
+/// What [`dijkstra`] should do with a node once it knows how far away it is.
+enum Visit {
+    Expand,
+    /// Keep the node's distance but don't go any further from it.
+    Skip,
+    /// Forget the node and stop searching.
+    Stop,
+}
+
+/// The distance to every node from the nearest of `starts`, for the nodes the search got to.
+fn dijkstra<N, C, FN, IN>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: FN,
+    mut visit: impl FnMut(&N, C) -> Visit,
+) -> HashMap<N, C>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+{
+    // everything's stored by index so that the heap doesn't need N: Ord
+    let mut nodes = Vec::new();
+    let mut indices = HashMap::new();
+    let mut distances = Vec::new();
+    let mut settled = Vec::new();
+    let mut to_visit = BinaryHeap::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = indices.entry(start) {
+            let index = nodes.len();
+            nodes.push(entry.key().clone());
+            entry.insert(index);
+            distances.push(C::zero());
+            settled.push(false);
+            to_visit.push(Reverse((C::zero(), index)));
+        }
+    }
+
+    while let Some(Reverse((distance, index))) = to_visit.pop() {
+        if settled[index] || distance > distances[index] {
+            continue;
+        }
+        match visit(&nodes[index], distance) {
+            Visit::Stop => break,
+            Visit::Skip => {
+                settled[index] = true;
+                continue;
+            }
+            Visit::Expand => settled[index] = true,
+        }
+        for (neighbor, cost) in neighbors(&nodes[index]) {
+            let new_distance = distance + cost;
+            match indices.entry(neighbor) {
+                Entry::Occupied(entry) => {
+                    let neighbor_index = *entry.get();
+                    if new_distance < distances[neighbor_index] {
+                        distances[neighbor_index] = new_distance;
+                        to_visit.push(Reverse((new_distance, neighbor_index)));
+                    }
+                }
+                Entry::Vacant(entry) => {
+                    let neighbor_index = nodes.len();
+                    nodes.push(entry.key().clone());
+                    entry.insert(neighbor_index);
+                    distances.push(new_distance);
+                    settled.push(false);
+                    to_visit.push(Reverse((new_distance, neighbor_index)));
+                }
+            }
+        }
+    }
+
+    nodes
+        .into_iter()
+        .zip(distances)
+        .zip(settled)
+        .filter(|&(_, settled)| settled)
+        .map(|(node_distance, _)| node_distance)
+        .collect()
+}
+
+/// Find every shortest path from `start` to a node that `success` says yes to.
+///
+/// `predecessors` is the other way round from `successors`: the nodes one step before a node,
+/// and what that step costs. This runs Dijkstra forward from `start` to find the nearest goals,
+/// then backward from them. A node is on a shortest path when its distances from the start and
+/// to a goal add up to the shortest path's cost, and the same goes for the edges.
+///
+/// Costs have to be positive. Returns `None` if no goal can be reached.
+///
+/// ```
+/// # use aoc_2024::graph::shortest_paths;
+/// // 0 -> 1 -> 3 and 0 -> 2 -> 3 both cost 2, but 0 -> 3 directly costs 5
+/// let edges = [(0, 1, 1), (0, 2, 1), (1, 3, 1), (2, 3, 1), (0, 3, 5)];
+/// let paths = shortest_paths(
+///     &0,
+///     |&n| edges.iter().filter(move |e| e.0 == n).map(|&(_, to, cost)| (to, cost)),
+///     |&n| edges.iter().filter(move |e| e.1 == n).map(|&(from, _, cost)| (from, cost)),
+///     |&n| n == 3,
+/// )
+/// .unwrap();
+/// assert_eq!(paths.cost(), 2);
+/// assert_eq!(paths.count_paths::<u64>(), 2);
+/// assert_eq!(paths.edges().count(), 4);
+/// assert_eq!(paths.distance_to_goal(&1), Some(1));
+/// ```
+pub fn shortest_paths<N, C, FN, IN, FP, IP, FS>(
+    start: &N,
+    successors: FN,
+    mut predecessors: FP,
+    mut success: FS,
+) -> Option<ShortestPaths<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FP: FnMut(&N) -> IP,
+    IP: IntoIterator<Item = (N, C)>,
+    FS: FnMut(&N) -> bool,
+{
+    let mut best_cost = None;
+    let mut goals = Vec::new();
+    let from_start = dijkstra([start.clone()], successors, |node, distance| {
+        if best_cost.is_some_and(|best_cost| distance > best_cost) {
+            Visit::Stop
+        } else if success(node) {
+            best_cost = Some(distance);
+            goals.push(node.clone());
+            Visit::Skip
+        } else {
+            Visit::Expand
+        }
+    });
+    let cost = best_cost?;
+    // nothing further than `cost` from a goal can be on a shortest path
+    let to_goal = dijkstra(goals.iter().cloned(), &mut predecessors, |_, distance| {
+        if distance > cost {
+            Visit::Stop
+        } else {
+            Visit::Expand
+        }
+    });
+
+    let on_path = |node: &N| {
+        from_start
+            .get(node)
+            .zip(to_goal.get(node))
+            .is_some_and(|(&from_start, &to_goal)| from_start + to_goal == cost)
+    };
+    let mut nodes: Vec<N> = from_start
+        .keys()
+        .filter(|node| on_path(node))
+        .cloned()
+        .collect();
+    nodes.sort_by_key(|node| from_start[node]);
+    let parents = nodes
+        .iter()
+        .map(|node| {
+            let node_parents = predecessors(node)
+                .into_iter()
+                .filter(|(parent, step_cost)| {
+                    on_path(parent) && from_start[parent] + *step_cost == from_start[node]
+                })
+                .map(|(parent, _)| parent)
+                .collect();
+            (node.clone(), node_parents)
+        })
+        .collect();
+    Some(ShortestPaths {
+        cost,
+        goals,
+        nodes,
+        parents,
+        from_start,
+        to_goal,
+    })
+}
+
+impl<N: Eq + Hash, C: Copy> ShortestPaths<N, C> {
+    /// How long the shortest paths are.
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    /// The goals that are reachable at the lowest cost.
+    pub fn goals(&self) -> &[N] {
+        &self.goals
+    }
+
+    /// Every node that's on at least one shortest path, closest to the start first.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// How far `node` is from the start, if the search got that far.
+    pub fn distance_from_start(&self, node: &N) -> Option<C> {
+        self.from_start.get(node).copied()
+    }
+
+    /// How far `node` is from the nearest goal, if it's close enough to be worth knowing
+    /// (no further than the shortest path's cost).
+    pub fn distance_to_goal(&self, node: &N) -> Option<C> {
+        self.to_goal.get(node).copied()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.parents.contains_key(node)
+    }
+
+    /// The nodes just before `node` on shortest paths through it.
+    pub fn parents(&self, node: &N) -> &[N] {
+        self.parents.get(node).map_or(&[], |parents| parents)
+    }
+
+    /// Every `(from, to)` step that's on at least one shortest path.
+    pub fn edges(&self) -> impl Iterator<Item = (&N, &N)> {
+        self.parents
+            .iter()
+            .flat_map(|(node, parents)| parents.iter().map(move |parent| (parent, node)))
+    }
+
+    /// How many different shortest paths there are, without listing them.
+    /// Pick a `K` that's big enough; there can be a lot.
+    pub fn count_paths<K: Zero + One + Clone>(&self) -> K {
+        let mut counts: HashMap<&N, K> = HashMap::with_capacity(self.nodes.len());
+        for node in &self.nodes {
+            let parents = self.parents(node);
+            let count = if parents.is_empty() {
+                // only the start has no parents
+                K::one()
+            } else {
+                parents
+                    .iter()
+                    .fold(K::zero(), |total, parent| total + counts[parent].clone())
+            };
+            counts.insert(node, count);
+        }
+        self.goals
+            .iter()
+            .fold(K::zero(), |total, goal| total + counts[goal].clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Directed edges with costs, and which way to look them up.
+    fn successors(edges: &[(u32, u32, u32)]) -> impl FnMut(&u32) -> Vec<(u32, u32)> + '_ {
+        |&n| {
+            edges
+                .iter()
+                .filter(|e| e.0 == n)
+                .map(|&(_, to, cost)| (to, cost))
+                .collect()
+        }
+    }
+
+    fn predecessors(edges: &[(u32, u32, u32)]) -> impl FnMut(&u32) -> Vec<(u32, u32)> + '_ {
+        |&n| {
+            edges
+                .iter()
+                .filter(|e| e.1 == n)
+                .map(|&(from, _, cost)| (from, cost))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn unreachable_goal() {
+        let edges = [(0, 1, 1), (2, 3, 1)];
+        let paths = shortest_paths(&0, successors(&edges), predecessors(&edges), |&n| n == 3);
+        assert!(paths.is_none());
+    }
+
+    #[test]
+    fn cycles_and_tied_goals() {
+        // a square 0 - 1 - 3 - 2 - 0 that can be gone round either way, with the goals
+        // 3 and 4 both 2 away and 5 further off
+        let mut edges = vec![
+            (0, 1, 1),
+            (1, 3, 1),
+            (3, 2, 1),
+            (2, 0, 1),
+            (2, 4, 1),
+            (4, 5, 1),
+        ];
+        edges.extend(edges.clone().into_iter().map(|(a, b, cost)| (b, a, cost)));
+        let goal = |&n: &u32| n >= 3;
+        let paths = shortest_paths(&0, successors(&edges), predecessors(&edges), goal).unwrap();
+        assert_eq!(paths.cost(), 2);
+        let mut goals = paths.goals().to_vec();
+        goals.sort();
+        assert_eq!(goals, [3, 4]);
+        // 0 1 3, 0 2 3 and 0 2 4
+        assert_eq!(paths.count_paths::<u64>(), 3);
+        let mut nodes: Vec<_> = paths.nodes().copied().collect();
+        nodes[1..3].sort();
+        nodes[3..].sort();
+        assert_eq!(nodes, [0, 1, 2, 3, 4]);
+        assert_eq!(paths.parents(&3).len(), 2);
+        assert!(!paths.contains(&5));
+        assert_eq!(paths.distance_to_goal(&0), Some(2));
+    }
+
+    #[test]
+    fn deep_chain() {
+        const LENGTH: u32 = 200_000;
+        let paths = shortest_paths(
+            &0,
+            |&n| (n < LENGTH).then_some((n + 1, 1)),
+            |&n| (n > 0).then(|| (n - 1, 1)),
+            |&n| n == LENGTH,
+        )
+        .unwrap();
+        assert_eq!(paths.cost(), LENGTH);
+        assert_eq!(paths.count_paths::<u64>(), 1);
+        assert_eq!(paths.edges().count(), LENGTH as usize);
+    }
+}
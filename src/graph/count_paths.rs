@@ -0,0 +1,287 @@
+//! This started out copy-pasted from pathfinding/directed/count_paths.rs,
+//! but with usage of `usize` replaced with a type parameter based on `num_traits`.
+//! It's since been made iterative so deep graphs don't overflow the stack,
+//! and it notices cycles instead of recursing forever.
+
+use num_traits::{One, Zero};
+use std::{
+    collections::{hash_map::RandomState, HashMap},
+    error::Error,
+    fmt::{self, Display},
+    hash::{BuildHasher, Hash},
+};
+
+/// There's a cycle reachable from the start, so there could be infinitely many paths.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CycleError;
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the graph has a cycle, so its paths can't be counted")
+    }
+}
+
+impl Error for CycleError {}
+
+/// A depth-first search with an explicit stack instead of recursion.
+/// Everything is stored by index once it's been found.
+struct Search<N, I, S> {
+    indices: HashMap<N, usize, S>,
+    nodes: Vec<N>,
+    successors: Vec<Vec<usize>>,
+    finished: Vec<bool>,
+    /// Every node comes after all of its successors.
+    postorder: Vec<usize>,
+    stack: Vec<(usize, I)>,
+}
+
+impl<N, I, S> Search<N, I, S>
+where
+    N: Eq + Hash + Clone,
+    I: Iterator<Item = N>,
+    S: BuildHasher,
+{
+    /// Nodes that `is_end` says yes to aren't explored any further.
+    fn run<FN, IN, FE>(
+        start: N,
+        mut successors: FN,
+        mut is_end: FE,
+        hasher: S,
+    ) -> Result<Search<N, I, S>, CycleError>
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N, IntoIter = I>,
+        FE: FnMut(&N) -> bool,
+    {
+        let mut search = Search {
+            indices: HashMap::with_hasher(hasher),
+            nodes: Vec::new(),
+            successors: Vec::new(),
+            finished: Vec::new(),
+            postorder: Vec::new(),
+            stack: Vec::new(),
+        };
+        search.add(start, &mut successors, &mut is_end);
+
+        while let Some((index, node_successors)) = search.stack.last_mut() {
+            let index = *index;
+            let Some(successor) = node_successors.next() else {
+                search.stack.pop();
+                search.finished[index] = true;
+                search.postorder.push(index);
+                continue;
+            };
+            let successor_index = match search.indices.get(&successor) {
+                // it's still on the stack, so we've gone round in a circle
+                Some(&successor_index) if !search.finished[successor_index] => {
+                    return Err(CycleError)
+                }
+                Some(&successor_index) => successor_index,
+                None => search.add(successor, &mut successors, &mut is_end),
+            };
+            search.successors[index].push(successor_index);
+        }
+        Ok(search)
+    }
+
+    fn add<FN, IN, FE>(&mut self, node: N, successors: &mut FN, is_end: &mut FE) -> usize
+    where
+        FN: FnMut(&N) -> IN,
+        IN: IntoIterator<Item = N, IntoIter = I>,
+        FE: FnMut(&N) -> bool,
+    {
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.successors.push(Vec::new());
+        if is_end(&node) {
+            self.finished.push(true);
+            self.postorder.push(index);
+        } else {
+            self.finished.push(false);
+            self.stack.push((index, successors(&node).into_iter()));
+        }
+        self.nodes.push(node);
+        index
+    }
+}
+
+/// Count the number of paths from `start` to any node that `success` says yes to.
+/// Paths stop at the first successful node they reach.
+///
+/// Returns an error if there's a cycle reachable from `start` (even one that doesn't lead anywhere),
+/// since then there might be infinitely many paths.
+///
+/// ```
+/// # use aoc_2024::graph::{count_paths, CycleError};
+/// // 0 -> 1 -> 3 and 0 -> 2 -> 3
+/// let edges = [(0, 1), (0, 2), (1, 3), (2, 3)];
+/// let successors = |&n: &i32| edges.iter().filter(move |e| e.0 == n).map(|e| e.1);
+/// assert_eq!(count_paths(0, successors, |&n| n == 3), Ok(2u64));
+///
+/// let cycle = |&n: &i32| [(n + 1) % 3];
+/// assert_eq!(count_paths::<_, _, _, _, u64>(0, cycle, |&n| n == 5), Err(CycleError));
+/// ```
+pub fn count_paths<N, FN, IN, FS, K>(start: N, successors: FN, success: FS) -> Result<K, CycleError>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+    K: Zero + One + Clone,
+{
+    count_paths_with_hasher(start, successors, success, RandomState::new())
+}
+
+/// Like [`count_paths`], but using `hasher` for the cache of nodes that have been seen.
+pub fn count_paths_with_hasher<N, FN, IN, FS, K, S>(
+    start: N,
+    successors: FN,
+    mut success: FS,
+    hasher: S,
+) -> Result<K, CycleError>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FS: FnMut(&N) -> bool,
+    K: Zero + One + Clone,
+    S: BuildHasher,
+{
+    let search = Search::run(start, successors, |node: &N| success(node), hasher)?;
+
+    // successors always come first in postorder, so their counts are ready in time
+    let mut counts = vec![K::zero(); search.nodes.len()];
+    for &index in &search.postorder {
+        counts[index] = if success(&search.nodes[index]) {
+            K::one()
+        } else {
+            search.successors[index]
+                .iter()
+                .fold(K::zero(), |total, &successor| {
+                    total + counts[successor].clone()
+                })
+        };
+    }
+    // the start is always found first
+    Ok(counts.swap_remove(0))
+}
+
+/// Count the number of paths from `start` to each node that `is_target` says yes to,
+/// all at once. Paths stop at the first target they reach, and targets that can't be
+/// reached are left out.
+///
+/// Returns an error if there's a cycle reachable from `start`, like [`count_paths`].
+pub fn count_paths_to_all<N, FN, IN, FT, K>(
+    start: N,
+    successors: FN,
+    is_target: FT,
+) -> Result<HashMap<N, K>, CycleError>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FT: FnMut(&N) -> bool,
+    K: Zero + One + Clone,
+{
+    count_paths_to_all_with_hasher(start, successors, is_target, RandomState::new())
+}
+
+/// Like [`count_paths_to_all`], but using `hasher` for the cache of nodes that have been seen
+/// and for the map of targets that comes back.
+pub fn count_paths_to_all_with_hasher<N, FN, IN, FT, K, S>(
+    start: N,
+    successors: FN,
+    mut is_target: FT,
+    hasher: S,
+) -> Result<HashMap<N, K, S>, CycleError>
+where
+    N: Eq + Hash + Clone,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FT: FnMut(&N) -> bool,
+    K: Zero + One + Clone,
+    S: BuildHasher + Clone,
+{
+    let search = Search::run(
+        start,
+        successors,
+        |node: &N| is_target(node),
+        hasher.clone(),
+    )?;
+
+    // going backwards through postorder visits every node before its successors
+    let mut counts = vec![K::zero(); search.nodes.len()];
+    counts[0] = K::one();
+    for &index in search.postorder.iter().rev() {
+        for &successor in &search.successors[index] {
+            counts[successor] = counts[successor].clone() + counts[index].clone();
+        }
+    }
+
+    let mut target_counts = HashMap::with_hasher(hasher);
+    for (node, count) in search.nodes.into_iter().zip(counts) {
+        if is_target(&node) {
+            target_counts.insert(node, count);
+        }
+    }
+    Ok(target_counts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(u32, u32)]) -> impl FnMut(&u32) -> Vec<u32> + '_ {
+        |&n| {
+            edges
+                .iter()
+                .filter(|&&(from, _)| from == n)
+                .map(|&(_, to)| to)
+                .collect()
+        }
+    }
+
+    #[test]
+    fn cycles() {
+        // 1 <-> 2 never gets to 3, but it's still a cycle
+        let edges = [(0, 1), (1, 2), (2, 1), (0, 3)];
+        assert_eq!(
+            count_paths::<_, _, _, _, u64>(0, graph(&edges), |&n| n == 3),
+            Err(CycleError)
+        );
+        assert_eq!(
+            count_paths_to_all::<_, _, _, _, u64>(0, graph(&edges), |&n| n == 3),
+            Err(CycleError)
+        );
+        // paths stop at the target, so a cycle through it doesn't count
+        let edges = [(0, 1), (1, 0)];
+        assert_eq!(count_paths(0, graph(&edges), |&n| n == 1), Ok(1u64));
+    }
+
+    #[test]
+    fn several_sinks() {
+        // 0 -> 1 -> 3, 0 -> 2 -> 3, 1 -> 4 and 2 -> 4 -> 5
+        let edges = [(0, 1), (0, 2), (1, 3), (2, 3), (1, 4), (2, 4), (4, 5)];
+        let sinks = |&n: &u32| n == 3 || n == 5 || n == 6;
+        let counts = count_paths_to_all::<_, _, _, _, u64>(0, graph(&edges), sinks).unwrap();
+        assert_eq!(counts, HashMap::from([(3, 2), (5, 2)]));
+        assert_eq!(count_paths(0, graph(&edges), sinks), Ok(4u64));
+        // the start is a target too
+        let counts = count_paths_to_all::<_, _, _, _, u64>(0, graph(&edges), |_| true).unwrap();
+        assert_eq!(counts, HashMap::from([(0, 1)]));
+    }
+
+    #[test]
+    fn deep_chain() {
+        // far too deep for a recursive search on a test thread's stack
+        const LENGTH: u32 = 200_000;
+        let successors = |&n: &u32| (n < LENGTH).then_some(n + 1);
+        assert_eq!(count_paths(0, successors, |&n| n == LENGTH), Ok(1u64));
+        let counts =
+            count_paths_to_all::<_, _, _, _, u64>(0, successors, |&n| n % 50_000 == 0).unwrap();
+        assert_eq!(counts, HashMap::from([(0, 1)]));
+        let counts =
+            count_paths_to_all::<_, _, _, _, u64>(1, successors, |&n| n == LENGTH).unwrap();
+        assert_eq!(counts, HashMap::from([(LENGTH, 1)]));
+    }
+}
@@ -0,0 +1,125 @@
+use anyhow::{anyhow, Result};
+use std::{
+    env,
+    ffi::OsString,
+    fs::{self, File},
+    io::{self, BufRead, BufReader, Cursor, Read},
+    path::{Path, PathBuf},
+};
+
+mod cache;
+
+pub use cache::{Curl, Http, InputCache, CACHE_DIR_VAR, SESSION_VAR, YEAR};
+
+/// If there's no input on the command line, it's read out of this environment variable instead.
+pub const INPUT_VAR: &str = "AOC_INPUT";
+
+/// Where a puzzle input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Path(PathBuf),
+    Stdin,
+    Buffer(Vec<u8>),
+    /// The contents of an environment variable (not a path stored in one).
+    Env(OsString),
+}
+
+impl Input {
+    /// Interpret a command-line argument: `-` is stdin, anything else is a path.
+    pub fn from_arg(arg: impl Into<OsString>) -> Input {
+        let arg = arg.into();
+        if arg == "-" {
+            Input::Stdin
+        } else {
+            Input::Path(arg.into())
+        }
+    }
+
+    /// The input for a day: `arg` if there is one, otherwise [`INPUT_VAR`] if it's set,
+    /// otherwise the real input from the [`InputCache`] (downloading it if need be).
+    pub fn for_day(day: u32, arg: Option<String>) -> Result<Input> {
+        if let Some(arg) = arg {
+            Ok(Input::from_arg(arg))
+        } else if env::var_os(INPUT_VAR).is_some() {
+            Ok(Input::Env(INPUT_VAR.into()))
+        } else {
+            Ok(Input::Path(InputCache::from_env()?.get(YEAR, day)?))
+        }
+    }
+
+    pub fn buf_read(self) -> Result<Box<dyn BufRead>> {
+        Ok(match self {
+            Input::Path(path) => Box::new(BufReader::new(File::open(path)?)),
+            Input::Stdin => Box::new(io::stdin().lock()),
+            Input::Buffer(buffer) => Box::new(Cursor::new(buffer)),
+            Input::Env(var) => Box::new(Cursor::new(env_string(&var)?.into_bytes())),
+        })
+    }
+
+    pub fn read_to_string(self) -> Result<String> {
+        Ok(match self {
+            Input::Path(path) => fs::read_to_string(path)?,
+            Input::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                input
+            }
+            Input::Buffer(buffer) => String::from_utf8(buffer)?,
+            Input::Env(var) => env_string(&var)?,
+        })
+    }
+}
+
+/// Where `run.sh` looks for an input: `src/bin/NN/NAME.txt`.
+pub fn day_input_path(day: u32, name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("src/bin")
+        .join(format!("{day:02}"))
+        .join(format!("{name}.txt"))
+}
+
+/// The input file for a day out of `src/bin/NN/NAME.txt`, for running lots of days at once.
+/// The real input (`NAME` is `input`) comes from the [`InputCache`] if it isn't there,
+/// as long as [`SESSION_VAR`] is set. `None` means there's no input to run.
+pub fn find_day_input(day: u32, name: &str) -> Result<Option<PathBuf>> {
+    let path = day_input_path(day, name);
+    if path.exists() {
+        Ok(Some(path))
+    } else if name != "input" || env::var_os(SESSION_VAR).is_none() {
+        Ok(None)
+    } else {
+        Ok(Some(InputCache::from_env()?.get(YEAR, day)?))
+    }
+}
+
+/// Extra args for a day that go with an input file, from a `.args` file next to it
+/// (like `input.args` for `input.txt`), separated by whitespace. No file means no args.
+pub fn sidecar_args(input: &Path) -> Result<Vec<String>> {
+    let args_path = input.with_extension("args");
+    if !args_path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(fs::read_to_string(&args_path)?
+        .split_whitespace()
+        .map(String::from)
+        .collect())
+}
+
+fn env_string(var: &OsString) -> Result<String> {
+    env::var_os(var)
+        .ok_or_else(|| anyhow!("{} is not set", var.to_string_lossy()))?
+        .into_string()
+        .map_err(|_| anyhow!("{} is not valid UTF-8", var.to_string_lossy()))
+}
+
+impl From<&str> for Input {
+    fn from(buffer: &str) -> Input {
+        Input::Buffer(buffer.into())
+    }
+}
+
+impl From<String> for Input {
+    fn from(buffer: String) -> Input {
+        Input::Buffer(buffer.into())
+    }
+}
@@ -0,0 +1,312 @@
+use anyhow::{anyhow, Context, Result};
+use std::{
+    env, fs,
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+/// The session cookie from logging in to adventofcode.com, for downloading inputs.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Where downloaded inputs go. If this isn't set they go in `$XDG_CACHE_HOME/aoc`
+/// or `~/.cache/aoc`.
+pub const CACHE_DIR_VAR: &str = "AOC_CACHE_DIR";
+
+/// The year these solutions are for.
+pub const YEAR: u32 = 2024;
+
+const BASE_URL: &str = "https://adventofcode.com";
+/// Eric asks that automated requests say where they come from.
+const USER_AGENT: &str = "github.com/Quantaly/aoc-2024 input cache";
+
+/// Something that can make GET requests, so that tests don't have to talk to the real site.
+pub trait Http {
+    /// The body of the response, or an error if it wasn't a success.
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>>;
+}
+
+/// Makes requests by running `curl`, which saves on pulling in a whole HTTP and TLS stack.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Curl;
+
+impl Http for Curl {
+    fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>> {
+        // headers go through stdin so that the session doesn't show up in the process list
+        let mut curl = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--header", "@-", url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .context("couldn't run curl")?;
+        let mut stdin = curl.stdin.take().expect("stdin is piped");
+        for (name, value) in headers {
+            writeln!(stdin, "{name}: {value}")?;
+        }
+        drop(stdin);
+        let output = curl.wait_with_output()?;
+        if !output.status.success() {
+            return Err(anyhow!(
+                "couldn't get {url}: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        Ok(output.stdout)
+    }
+}
+
+/// Downloaded puzzle inputs, kept in `DIR/YEAR/DD/USER.txt` so that each one only
+/// ever gets downloaded once.
+///
+/// Everyone gets a different input, so `USER` comes from the session
+/// (hashed, so the session itself doesn't end up in a file name).
+#[derive(Debug, Clone)]
+pub struct InputCache<H = Curl> {
+    dir: PathBuf,
+    session: Option<String>,
+    base_url: String,
+    http: H,
+}
+
+impl InputCache {
+    pub fn new(dir: impl Into<PathBuf>) -> InputCache {
+        InputCache {
+            dir: dir.into(),
+            session: None,
+            base_url: BASE_URL.into(),
+            http: Curl,
+        }
+    }
+
+    /// Set up from [`CACHE_DIR_VAR`] and [`SESSION_VAR`].
+    pub fn from_env() -> Result<InputCache> {
+        let dir = match env::var_os(CACHE_DIR_VAR) {
+            Some(dir) => PathBuf::from(dir),
+            None => env::var_os("XDG_CACHE_HOME")
+                .map(PathBuf::from)
+                .or_else(|| Some(PathBuf::from(env::var_os("HOME")?).join(".cache")))
+                .ok_or_else(|| anyhow!("set {CACHE_DIR_VAR} to say where to keep inputs"))?
+                .join("aoc"),
+        };
+        let cache = InputCache::new(dir);
+        Ok(match env::var(SESSION_VAR) {
+            Ok(session) => cache.session(session),
+            Err(_) => cache,
+        })
+    }
+}
+
+impl<H: Http> InputCache<H> {
+    pub fn session(mut self, session: impl Into<String>) -> Self {
+        self.session = Some(session.into().trim().into());
+        self
+    }
+
+    /// Where to download from instead of adventofcode.com, like a mock server.
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').into();
+        self
+    }
+
+    pub fn http<H2: Http>(self, http: H2) -> InputCache<H2> {
+        InputCache {
+            dir: self.dir,
+            session: self.session,
+            base_url: self.base_url,
+            http,
+        }
+    }
+
+    fn session_or_err(&self) -> Result<&str> {
+        self.session
+            .as_deref()
+            .ok_or_else(|| anyhow!("set {SESSION_VAR} to the session cookie from adventofcode.com"))
+    }
+
+    /// Where the input for a day goes, whether or not it's been downloaded yet.
+    pub fn path(&self, year: u32, day: u32) -> Result<PathBuf> {
+        let user = format!("{:016x}", fnv1a(self.session_or_err()?.as_bytes()));
+        Ok(self
+            .dir
+            .join(year.to_string())
+            .join(format!("{day:02}"))
+            .join(format!("{user}.txt")))
+    }
+
+    /// The path to the input for a day, downloading it first if it isn't there yet.
+    pub fn get(&self, year: u32, day: u32) -> Result<PathBuf> {
+        let path = self.path(year, day)?;
+        if path.exists() {
+            return Ok(path);
+        }
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let cookie = format!("session={}", self.session_or_err()?);
+        let input = self
+            .http
+            .get(&url, &[("Cookie", &cookie), ("User-Agent", USER_AGENT)])?;
+        write_atomically(&path, &input)
+            .with_context(|| format!("couldn't save input to {}", path.display()))?;
+        Ok(path)
+    }
+}
+
+/// So that something reading the cache never sees half an input.
+fn write_atomically(path: &Path, contents: &[u8]) -> Result<()> {
+    let dir = path.parent().expect("inputs are always in a directory");
+    fs::create_dir_all(dir)?;
+    let partial = path.with_extension(format!("partial.{}", std::process::id()));
+    fs::write(&partial, contents)?;
+    fs::rename(&partial, path)?;
+    Ok(())
+}
+
+/// Doesn't change between Rust versions, unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        io::{BufRead, BufReader, Read},
+        net::{TcpListener, TcpStream},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir = env::temp_dir().join(format!("aoc-cache-{name}-{}", std::process::id()));
+            let _ = fs::remove_dir_all(&dir);
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Answers every request for day 1 with an input, as long as the session is right,
+    /// and counts how many requests it got.
+    fn mock_server() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+                let request: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map_while(|line| line.ok().filter(|line| !line.is_empty()))
+                    .collect();
+                let (status, body) = if request[0] != "GET /2024/day/1/input HTTP/1.1" {
+                    ("404 Not Found", "no such puzzle\n")
+                } else if !request.iter().any(|line| line == "Cookie: session=abc") {
+                    ("400 Bad Request", "please log in\n")
+                } else {
+                    ("200 OK", "3   4\n4   3\n")
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+        (url, requests)
+    }
+
+    /// Just enough HTTP to talk to the mock server, so these don't need curl.
+    #[derive(Clone)]
+    struct PlainHttp;
+
+    impl Http for PlainHttp {
+        fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>> {
+            let url = url
+                .strip_prefix("http://")
+                .expect("the mock server is http");
+            let (host, path) = url.split_at(url.find('/').unwrap_or(url.len()));
+            let mut stream = TcpStream::connect(host)?;
+            write!(stream, "GET {path} HTTP/1.1\r\nHost: {host}\r\n")?;
+            for (name, value) in headers {
+                write!(stream, "{name}: {value}\r\n")?;
+            }
+            write!(stream, "Connection: close\r\n\r\n")?;
+            let mut response = Vec::new();
+            stream.read_to_end(&mut response)?;
+            let split = response
+                .windows(4)
+                .position(|window| window == b"\r\n\r\n")
+                .ok_or_else(|| anyhow!("no end to the headers"))?;
+            let status = String::from_utf8_lossy(&response[..split]);
+            let status = status.lines().next().unwrap_or_default();
+            if !status
+                .split(' ')
+                .nth(1)
+                .is_some_and(|code| code.starts_with('2'))
+            {
+                return Err(anyhow!("couldn't get {url}: {status}"));
+            }
+            Ok(response.split_off(split + 4))
+        }
+    }
+
+    #[test]
+    fn downloads_once() -> Result<()> {
+        let dir = TempDir::new("once");
+        let (url, requests) = mock_server();
+        let cache = InputCache::new(&dir.0)
+            .session("abc")
+            .base_url(url)
+            .http(PlainHttp);
+        let path = cache.get(2024, 1)?;
+        assert_eq!(fs::read_to_string(&path)?, "3   4\n4   3\n");
+        assert_eq!(cache.get(2024, 1)?, path);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn failed_downloads() {
+        let dir = TempDir::new("failed");
+        let (url, requests) = mock_server();
+        let cache = InputCache::new(&dir.0).base_url(url).http(PlainHttp);
+        assert!(cache.get(2024, 1).is_err(), "no session");
+        assert!(cache.clone().session("abd").get(2024, 1).is_err());
+        assert!(cache.clone().session("abc").get(2024, 2).is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+        // nothing half-written is left lying around
+        assert!(!dir.0.exists() || fs::read_dir(&dir.0).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn users_get_their_own_inputs() -> Result<()> {
+        #[derive(Clone)]
+        struct Echo;
+        impl Http for Echo {
+            fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>> {
+                Ok(format!("{url} {headers:?}").into_bytes())
+            }
+        }
+        let dir = TempDir::new("users");
+        let cache = InputCache::new(&dir.0).http(Echo);
+        let alice = cache.clone().session("alice").get(2024, 7)?;
+        let bob = cache.clone().session("bob").get(2024, 7)?;
+        assert_ne!(alice, bob);
+        assert!(alice.starts_with(dir.0.join("2024/07")));
+        assert!(fs::read_to_string(&alice)?.contains("session=alice"));
+        Ok(())
+    }
+}
@@ -1,22 +1,24 @@
-use anyhow::{anyhow, Result};
-use std::{
-    env,
-    fs::{self, File},
-    io::{BufRead, BufReader},
-};
+use anyhow::Result;
+use input::Input;
+use std::{env, io::BufRead};
 
+pub mod bench;
 pub mod board;
+pub mod days;
+pub mod graph;
+pub mod input;
+pub mod options;
+pub mod render;
+pub mod simulation;
+pub mod solution;
 
-fn input_path() -> Result<String> {
-    env::args()
-        .nth(1)
-        .ok_or_else(|| anyhow!("need a command-line argument"))
+/// The input for a day, from the first command-line argument or wherever
+/// [`Input::for_day`] finds it if there isn't one.
+pub fn input_buf_read(day: u32) -> Result<impl BufRead> {
+    Input::for_day(day, env::args().nth(1))?.buf_read()
 }
 
-pub fn input_buf_read() -> Result<impl BufRead> {
-    Ok(BufReader::new(File::open(input_path()?)?))
-}
-
-pub fn input_string() -> Result<String> {
-    fs::read_to_string(input_path()?).map_err(Into::into)
+/// Like [`input_buf_read`], but all at once.
+pub fn input_string(day: u32) -> Result<String> {
+    Input::for_day(day, env::args().nth(1))?.read_to_string()
 }
@@ -0,0 +1,167 @@
+//! Named options for days that need more than just an input, like day 14's `--width 101`.
+
+use anyhow::{anyhow, Context, Result};
+use serde::Serialize;
+use std::{collections::HashMap, error::Error, fmt::Write, str::FromStr};
+
+/// One option a day takes. Days list these so that front ends can find out what to ask for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct OptionSpec {
+    /// Without the `--`.
+    pub name: &'static str,
+    /// What sort of value it takes, like `N`, for the help.
+    pub value: &'static str,
+    pub help: &'static str,
+    /// Options without a default have to be given.
+    pub default: Option<&'static str>,
+}
+
+/// The values given for a day's options, checked against what it says it takes.
+///
+/// ```
+/// # use aoc_2024::options::{OptionSpec, Options};
+/// const SPECS: &[OptionSpec] = &[
+///     OptionSpec { name: "width", value: "N", help: "how wide", default: Some("101") },
+///     OptionSpec { name: "height", value: "N", help: "how tall", default: Some("103") },
+/// ];
+/// let options = Options::parse(SPECS, &["--width".into(), "11".into(), "--height=7".into()])?;
+/// assert_eq!((options.get::<i64>("width")?, options.get::<i64>("height")?), (11, 7));
+/// assert_eq!(Options::parse(SPECS, &[])?.get::<i64>("width")?, 101);
+/// # anyhow::Ok(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    specs: &'static [OptionSpec],
+    values: HashMap<&'static str, String>,
+}
+
+impl Options {
+    /// Read `--name value` or `--name=value` pairs out of `args`.
+    pub fn parse(specs: &'static [OptionSpec], args: &[String]) -> Result<Options> {
+        let mut values = HashMap::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let Some(arg) = arg.strip_prefix("--") else {
+                return Err(anyhow!("expected an option, got {arg}\n\n{}", help(specs)));
+            };
+            let (name, value) = match arg.split_once('=') {
+                Some((name, value)) => (name, value.to_string()),
+                None => (
+                    arg,
+                    args.next()
+                        .ok_or_else(|| anyhow!("--{arg} needs a value"))?
+                        .clone(),
+                ),
+            };
+            let spec = specs
+                .iter()
+                .find(|spec| spec.name == name)
+                .ok_or_else(|| anyhow!("there's no --{name} option\n\n{}", help(specs)))?;
+            values.insert(spec.name, value);
+        }
+        Ok(Options { specs, values })
+    }
+
+    /// The value of an option, or its default if it wasn't given.
+    pub fn get<T>(&self, name: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Error + Send + Sync + 'static,
+    {
+        let spec = self
+            .specs
+            .iter()
+            .find(|spec| spec.name == name)
+            .ok_or_else(|| anyhow!("there's no --{name} option"))?;
+        let value = self
+            .values
+            .get(name)
+            .map(String::as_str)
+            .or(spec.default)
+            .ok_or_else(|| anyhow!("specify --{name} {} ({})", spec.value, spec.help))?;
+        value
+            .parse()
+            .with_context(|| format!("--{name} should be {}, not {value}", spec.value))
+    }
+}
+
+/// A line for each option saying what it's for.
+pub fn help(specs: &[OptionSpec]) -> String {
+    if specs.is_empty() {
+        return "this day doesn't take any options".into();
+    }
+    let usages: Vec<_> = specs
+        .iter()
+        .map(|spec| format!("--{} {}", spec.name, spec.value))
+        .collect();
+    let width = usages.iter().map(String::len).max().unwrap_or(0);
+    let mut help = "options:".to_string();
+    for (spec, usage) in specs.iter().zip(usages) {
+        write!(help, "\n  {usage:<width$}  {}", spec.help).unwrap();
+        if let Some(default) = spec.default {
+            write!(help, " (default {default})").unwrap();
+        }
+    }
+    help
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SPECS: &[OptionSpec] = &[
+        OptionSpec {
+            name: "size",
+            value: "N",
+            help: "how big",
+            default: None,
+        },
+        OptionSpec {
+            name: "bytes",
+            value: "N",
+            help: "how many",
+            default: Some("1024"),
+        },
+    ];
+
+    fn args(args: &str) -> Vec<String> {
+        args.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn later_values_win() -> Result<()> {
+        let options = Options::parse(SPECS, &args("--size 6 --size=70"))?;
+        assert_eq!(options.get::<usize>("size")?, 70);
+        Ok(())
+    }
+
+    #[test]
+    fn missing_values() -> Result<()> {
+        let options = Options::parse(SPECS, &[])?;
+        assert_eq!(options.get::<usize>("bytes")?, 1024);
+        let err = options.get::<usize>("size").unwrap_err().to_string();
+        assert_eq!(err, "specify --size N (how big)");
+        assert!(Options::parse(SPECS, &args("--size")).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn bad_args() -> Result<()> {
+        for bad in ["70", "--width 70", "-size 70"] {
+            assert!(Options::parse(SPECS, &args(bad)).is_err(), "{bad}");
+        }
+        let options = Options::parse(SPECS, &args("--size lots"))?;
+        assert!(options.get::<usize>("size").is_err());
+        assert!(options.get::<usize>("width").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn help_lines() {
+        assert_eq!(
+            help(SPECS),
+            "options:\n  --size N   how big\n  --bytes N  how many (default 1024)"
+        );
+        assert_eq!(help(&[]), "this day doesn't take any options");
+    }
+}
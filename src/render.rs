@@ -0,0 +1,325 @@
+//! Drawing boards as text, colourful terminal output, or images, mostly for debugging,
+//! and recording simulations one frame at a time.
+
+use crate::board::{Board, Grid, Tile};
+use std::{collections::HashMap, fmt::Write};
+
+mod image;
+mod recorder;
+
+pub use image::Image;
+pub use recorder::{Recorder, RECORD_VAR};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+    pub const GRAY: Rgb = Rgb(128, 128, 128);
+    pub const RED: Rgb = Rgb(220, 50, 47);
+    pub const GREEN: Rgb = Rgb(80, 200, 80);
+    pub const BLUE: Rgb = Rgb(38, 139, 210);
+    pub const YELLOW: Rgb = Rgb(230, 200, 40);
+
+    /// What a cell looks like in an image when nothing else says otherwise:
+    /// walls are gray, empty space is black, and everything else is white.
+    pub fn default_for(glyph: u8) -> Rgb {
+        match glyph {
+            b'#' => Rgb::GRAY,
+            b'.' | b' ' => Rgb::BLACK,
+            _ => Rgb::WHITE,
+        }
+    }
+}
+
+/// Something that can be drawn as one character per cell.
+pub trait Canvas {
+    fn width(&self) -> usize;
+    fn height(&self) -> usize;
+    /// `None` for cells that aren't there, like the end of a short row in a [`Board`].
+    fn glyph(&self, pos: (usize, usize)) -> Option<u8>;
+}
+
+impl Canvas for Board {
+    fn width(&self) -> usize {
+        self.iter().map(|row| row.len()).max().unwrap_or(0)
+    }
+
+    fn height(&self) -> usize {
+        self.len()
+    }
+
+    fn glyph(&self, (i, j): (usize, usize)) -> Option<u8> {
+        self.get(i).and_then(|row| row.get(j)).copied()
+    }
+}
+
+impl<T: Tile> Canvas for Grid<T> {
+    fn width(&self) -> usize {
+        Grid::width(self)
+    }
+
+    fn height(&self) -> usize {
+        Grid::height(self)
+    }
+
+    fn glyph(&self, pos: (usize, usize)) -> Option<u8> {
+        self.get(pos).map(|&tile| tile.to_byte())
+    }
+}
+
+type ColourFn<'a> = Box<dyn Fn((usize, usize), u8) -> Option<Rgb> + 'a>;
+
+/// A board along with everything that's been drawn on top of it.
+pub struct Render<'a, C: Canvas + ?Sized> {
+    canvas: &'a C,
+    colour: Option<ColourFn<'a>>,
+    marks: HashMap<(usize, usize), (u8, Rgb)>,
+    highlights: HashMap<(usize, usize), Rgb>,
+    scale: usize,
+}
+
+impl<'a, C: Canvas + ?Sized> Render<'a, C> {
+    pub fn new(canvas: &'a C) -> Render<'a, C> {
+        Render {
+            canvas,
+            colour: None,
+            marks: HashMap::new(),
+            highlights: HashMap::new(),
+            scale: 1,
+        }
+    }
+
+    /// Pick a colour for each cell. This is the text colour in the terminal
+    /// and the pixel colour in images (falling back to [`Rgb::default_for`]).
+    pub fn colour_with(mut self, colour: impl Fn((usize, usize), u8) -> Option<Rgb> + 'a) -> Self {
+        self.colour = Some(Box::new(colour));
+        self
+    }
+
+    /// Draw `glyph` over each of `cells`, e.g. `O` along a path or `@` where a robot is.
+    pub fn mark(
+        mut self,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+        glyph: u8,
+        colour: Rgb,
+    ) -> Self {
+        self.marks
+            .extend(cells.into_iter().map(|pos| (pos, (glyph, colour))));
+        self
+    }
+
+    /// Colour in the background behind each of `cells` without changing what's drawn there.
+    /// Plain text can't show this; use [`mark`](Render::mark) for that.
+    pub fn highlight(
+        mut self,
+        cells: impl IntoIterator<Item = (usize, usize)>,
+        colour: Rgb,
+    ) -> Self {
+        self.highlights
+            .extend(cells.into_iter().map(|pos| (pos, colour)));
+        self
+    }
+
+    /// How many pixels wide each cell is in images.
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    fn glyph(&self, pos: (usize, usize)) -> Option<u8> {
+        self.marks
+            .get(&pos)
+            .map(|&(glyph, _)| glyph)
+            .or_else(|| self.canvas.glyph(pos))
+    }
+
+    fn foreground(&self, pos: (usize, usize), glyph: u8) -> Option<Rgb> {
+        self.marks
+            .get(&pos)
+            .map(|&(_, colour)| colour)
+            .or_else(|| self.colour.as_ref().and_then(|colour| colour(pos, glyph)))
+    }
+
+    fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.canvas.width();
+        (0..self.canvas.height()).flat_map(move |i| (0..width).map(move |j| (i, j)))
+    }
+
+    /// Work out what every cell looks like right now, so it can be kept after the board changes.
+    pub fn to_frame(&self) -> Frame {
+        let cells = self
+            .positions()
+            .map(|pos| {
+                let background = self.highlights.get(&pos).copied();
+                match self.glyph(pos) {
+                    Some(glyph) => Cell {
+                        glyph,
+                        foreground: self.foreground(pos, glyph),
+                        background,
+                    },
+                    None => Cell {
+                        glyph: b' ',
+                        foreground: None,
+                        background,
+                    },
+                }
+            })
+            .collect();
+        Frame {
+            width: self.canvas.width(),
+            height: self.canvas.height(),
+            cells,
+        }
+    }
+
+    /// One line per row, each ending in a line break. Missing cells are spaces.
+    pub fn to_text(&self) -> String {
+        self.to_frame().to_text()
+    }
+
+    /// Like [`to_text`](Render::to_text), but with 24-bit colour escape codes for the terminal.
+    pub fn to_ansi(&self) -> String {
+        self.to_frame().to_ansi()
+    }
+
+    /// Each cell becomes a `scale` by `scale` square of one colour.
+    pub fn to_image(&self) -> Image {
+        self.to_frame().to_image(self.scale)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    glyph: u8,
+    foreground: Option<Rgb>,
+    background: Option<Rgb>,
+}
+
+/// A snapshot of a [`Render`], made with [`Render::to_frame`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Frame {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Just the `(height, width)` cells starting at `top_left`, or less if that runs off the edge.
+    pub fn crop(&self, (top, left): (usize, usize), (height, width): (usize, usize)) -> Frame {
+        let (top, left) = (top.min(self.height), left.min(self.width));
+        let height = height.min(self.height - top);
+        let width = width.min(self.width - left);
+        let cells = (top..top + height)
+            .flat_map(|i| &self.cells[i * self.width + left..i * self.width + left + width])
+            .copied()
+            .collect();
+        Frame {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn rows(&self) -> impl Iterator<Item = &[Cell]> {
+        (0..self.height).map(|i| &self.cells[i * self.width..(i + 1) * self.width])
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for row in self.rows() {
+            text.extend(row.iter().map(|cell| char::from(cell.glyph)));
+            text.push('\n');
+        }
+        text
+    }
+
+    pub fn to_ansi(&self) -> String {
+        let mut text = String::new();
+        for row in self.rows() {
+            for cell in row {
+                if let Some(Rgb(r, g, b)) = cell.foreground {
+                    _ = write!(text, "\x1b[38;2;{r};{g};{b}m");
+                }
+                if let Some(Rgb(r, g, b)) = cell.background {
+                    _ = write!(text, "\x1b[48;2;{r};{g};{b}m");
+                }
+                text.push(cell.glyph.into());
+                text.push_str("\x1b[0m");
+            }
+            text.push('\n');
+        }
+        text
+    }
+
+    /// Highlights win over the glyph's colour, which wins over [`Rgb::default_for`].
+    pub fn to_image(&self, scale: usize) -> Image {
+        let scale = scale.max(1);
+        let mut image = Image::new(self.width * scale, self.height * scale, Rgb::BLACK);
+        for (index, cell) in self.cells.iter().enumerate() {
+            let (i, j) = (index / self.width, index % self.width);
+            let colour = cell
+                .background
+                .or(cell.foreground)
+                .unwrap_or_else(|| Rgb::default_for(cell.glyph));
+            image.fill_square((i * scale, j * scale), scale, colour);
+        }
+        image
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A wall, a path going through the top right, a robot, and a highlighted cell.
+    fn render(grid: &Grid<u8>) -> Render<'_, Grid<u8>> {
+        Render::new(grid)
+            .mark([(0, 1)], b'O', Rgb::GREEN)
+            .mark([(1, 1)], b'@', Rgb::RED)
+            .highlight([(1, 0)], Rgb::BLUE)
+    }
+
+    #[test]
+    fn text() {
+        let grid = Grid::<u8>::parse("#.\n..\n").unwrap();
+        assert_eq!(render(&grid).to_text(), "#O\n.@\n");
+        assert_eq!(Render::new(&grid).to_text(), "#.\n..\n");
+    }
+
+    #[test]
+    fn ansi() {
+        let grid = Grid::<u8>::parse("#.\n..\n").unwrap();
+        assert_eq!(
+            render(&grid).to_ansi(),
+            "#\x1b[0m\x1b[38;2;80;200;80mO\x1b[0m\n\
+             \x1b[48;2;38;139;210m.\x1b[0m\x1b[38;2;220;50;47m@\x1b[0m\n"
+        );
+    }
+
+    #[test]
+    fn ppm() {
+        let grid = Grid::<u8>::parse("#.\n..\n").unwrap();
+        let mut ppm = Vec::new();
+        render(&grid).to_image().write_ppm(&mut ppm).unwrap();
+        let mut expected = b"P6\n2 2\n255\n".to_vec();
+        expected.extend([128, 128, 128, 80, 200, 80, 38, 139, 210, 220, 50, 47]);
+        assert_eq!(ppm, expected);
+
+        let image = render(&grid).scale(2).to_image();
+        assert_eq!((image.width(), image.height()), (4, 4));
+        assert_eq!(
+            image.pixels()[..4],
+            [Rgb::GRAY, Rgb::GRAY, Rgb::GREEN, Rgb::GREEN]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use super::Rgb;
+use anyhow::{anyhow, Result};
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+/// A plain RGB image, ready to be written out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Image {
+        Image {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Pixels are `(row, column)` like board positions. Anything off the edge is ignored.
+    pub fn set(&mut self, (y, x): (usize, usize), colour: Rgb) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    pub fn fill_square(&mut self, (y, x): (usize, usize), size: usize, colour: Rgb) {
+        for dy in 0..size {
+            for dx in 0..size {
+                self.set((y + dy, x + dx), colour);
+            }
+        }
+    }
+
+    pub fn pixels(&self) -> &[Rgb] {
+        &self.pixels
+    }
+
+    /// The pixels as `r, g, b, r, g, b, ...`.
+    pub fn to_rgb_bytes(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|&Rgb(r, g, b)| [r, g, b])
+            .collect()
+    }
+
+    /// Binary PPM (P6), which is about as simple as image formats get.
+    pub fn write_ppm(&self, mut w: impl Write) -> Result<()> {
+        write!(w, "P6\n{} {}\n255\n", self.width, self.height)?;
+        w.write_all(&self.to_rgb_bytes())?;
+        Ok(())
+    }
+
+    pub fn write_png(&self, w: impl Write) -> Result<()> {
+        let mut encoder = png::Encoder::new(w, self.width.try_into()?, self.height.try_into()?);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header()?;
+        writer.write_image_data(&self.to_rgb_bytes())?;
+        writer.finish()?;
+        Ok(())
+    }
+
+    /// Write a `.ppm` or `.png` file, depending on the extension.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+        match extension {
+            Some("ppm") => self.write_ppm(BufWriter::new(File::create(path)?)),
+            Some("png") => self.write_png(BufWriter::new(File::create(path)?)),
+            _ => Err(anyhow!(
+                "don't know how to save an image as {}",
+                path.display()
+            )),
+        }
+    }
+}
@@ -0,0 +1,241 @@
+use super::Frame;
+use anyhow::{anyhow, Context, Result};
+use std::{
+    env,
+    fs::{self, File},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Where [`Recorder::from_env`] writes to. Nothing gets recorded if this isn't set.
+pub const RECORD_VAR: &str = "AOC_RECORD";
+const STRIDE_VAR: &str = "AOC_RECORD_STRIDE";
+const CROP_VAR: &str = "AOC_RECORD_CROP";
+const SCALE_VAR: &str = "AOC_RECORD_SCALE";
+
+enum Output {
+    Gif(PathBuf, Option<gif::Encoder<BufWriter<File>>>),
+    Cast(PathBuf, Option<BufWriter<File>>),
+    Images(PathBuf),
+}
+
+/// Collects the frames of a simulation and writes them out as it goes.
+///
+/// Where they go depends on the path it's made with:
+/// `.gif` makes an animated GIF, `.cast` makes an [asciinema](https://asciinema.org) recording,
+/// and anything else is a directory to fill with numbered PNGs.
+pub struct Recorder {
+    output: Option<Output>,
+    stride: usize,
+    crop: Option<((usize, usize), (usize, usize))>,
+    scale: usize,
+    frame_time: Duration,
+    steps: usize,
+    frames: usize,
+}
+
+impl Recorder {
+    pub fn new(path: impl Into<PathBuf>) -> Recorder {
+        let path = path.into();
+        let output = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("gif") => Output::Gif(path, None),
+            Some("cast") => Output::Cast(path, None),
+            _ => Output::Images(path),
+        };
+        Recorder {
+            output: Some(output),
+            ..Recorder::disabled()
+        }
+    }
+
+    /// A recorder that throws everything away, for when nobody's watching.
+    pub fn disabled() -> Recorder {
+        Recorder {
+            output: None,
+            stride: 1,
+            crop: None,
+            scale: 4,
+            frame_time: Duration::from_millis(100),
+            steps: 0,
+            frames: 0,
+        }
+    }
+
+    /// Set up from [`RECORD_VAR`], along with `AOC_RECORD_STRIDE` (keep every nth step),
+    /// `AOC_RECORD_CROP` (`top,left,height,width`) and `AOC_RECORD_SCALE` (pixels per cell).
+    ///
+    /// The day and part go on the end of the file name, so `AOC_RECORD=out.gif` makes
+    /// `out-day06-part1.gif` for day 6 and doesn't get written over by other days.
+    pub fn from_env(day: u32, part: u8) -> Result<Recorder> {
+        let Some(path) = env::var_os(RECORD_VAR) else {
+            return Ok(Recorder::disabled());
+        };
+        let mut recorder = Recorder::new(output_path(Path::new(&path), day, part));
+        if let Ok(stride) = env::var(STRIDE_VAR) {
+            recorder = recorder.stride(
+                stride
+                    .parse()
+                    .with_context(|| format!("bad {STRIDE_VAR}"))?,
+            );
+        }
+        if let Ok(crop) = env::var(CROP_VAR) {
+            let numbers = crop
+                .split(',')
+                .map(|n| n.trim().parse())
+                .collect::<Result<Vec<usize>, _>>()
+                .with_context(|| format!("bad {CROP_VAR}"))?;
+            let &[top, left, height, width] = &numbers[..] else {
+                return Err(anyhow!("{CROP_VAR} should be top,left,height,width"));
+            };
+            recorder = recorder.crop((top, left), (height, width));
+        }
+        if let Ok(scale) = env::var(SCALE_VAR) {
+            recorder = recorder.scale(scale.parse().with_context(|| format!("bad {SCALE_VAR}"))?);
+        }
+        Ok(recorder)
+    }
+
+    /// Only keep every `stride`th step, starting with the first.
+    pub fn stride(mut self, stride: usize) -> Self {
+        self.stride = stride.max(1);
+        self
+    }
+
+    /// Only keep the `(height, width)` cells starting at `top_left` of each frame.
+    pub fn crop(mut self, top_left: (usize, usize), size: (usize, usize)) -> Self {
+        self.crop = Some((top_left, size));
+        self
+    }
+
+    /// How many pixels wide each cell is in GIFs and images.
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    /// How long each frame stays up when it's played back.
+    pub fn frame_time(mut self, frame_time: Duration) -> Self {
+        self.frame_time = frame_time;
+        self
+    }
+
+    pub fn is_recording(&self) -> bool {
+        self.output.is_some()
+    }
+
+    /// Call this once per step of the simulation.
+    /// `draw` only gets called for steps that are kept, so this is cheap when nothing's recording.
+    pub fn step(&mut self, draw: impl FnOnce() -> Frame) -> Result<()> {
+        let Some(output) = &mut self.output else {
+            return Ok(());
+        };
+        let step = self.steps;
+        self.steps += 1;
+        if !step.is_multiple_of(self.stride) {
+            return Ok(());
+        }
+
+        let mut frame = draw();
+        if let Some((top_left, size)) = self.crop {
+            frame = frame.crop(top_left, size);
+        }
+        let frame_number = self.frames;
+        self.frames += 1;
+
+        match output {
+            Output::Gif(path, encoder) => {
+                let image = frame.to_image(self.scale);
+                let (width, height) = (image.width().try_into()?, image.height().try_into()?);
+                let encoder = match encoder {
+                    Some(encoder) => encoder,
+                    None => {
+                        let file = BufWriter::new(File::create(&*path)?);
+                        let mut new_encoder = gif::Encoder::new(file, width, height, &[])?;
+                        new_encoder.set_repeat(gif::Repeat::Infinite)?;
+                        encoder.insert(new_encoder)
+                    }
+                };
+                let mut gif_frame =
+                    gif::Frame::from_rgb_speed(width, height, &image.to_rgb_bytes(), 10);
+                gif_frame.delay = (self.frame_time.as_millis() / 10).try_into()?;
+                encoder.write_frame(&gif_frame)?;
+            }
+            Output::Cast(path, writer) => {
+                let writer = match writer {
+                    Some(writer) => writer,
+                    None => {
+                        let mut new_writer = BufWriter::new(File::create(&*path)?);
+                        writeln!(
+                            new_writer,
+                            r#"{{"version": 2, "width": {}, "height": {}}}"#,
+                            frame.width(),
+                            frame.height()
+                        )?;
+                        writer.insert(new_writer)
+                    }
+                };
+                // the terminal's in raw mode, so line breaks need a carriage return too
+                let mut screen = String::from(if frame_number == 0 {
+                    "\x1b[2J\x1b[H"
+                } else {
+                    "\x1b[H"
+                });
+                screen.push_str(&frame.to_ansi().replace('\n', "\r\n"));
+                let time = (self.frame_time * frame_number.try_into()?).as_secs_f64();
+                writeln!(
+                    writer,
+                    r#"[{time:.6}, "o", {}]"#,
+                    serde_json::to_string(&screen)?
+                )?;
+            }
+            Output::Images(dir) => {
+                if frame_number == 0 {
+                    fs::create_dir_all(&*dir)?;
+                }
+                frame
+                    .to_image(self.scale)
+                    .save(dir.join(format!("{frame_number:06}.png")))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Finish off whatever's being written. Dropping the recorder does this too,
+    /// but any errors get lost.
+    pub fn finish(self) -> Result<()> {
+        match self.output {
+            Some(Output::Gif(_, Some(encoder))) => encoder.into_inner()?.flush()?,
+            Some(Output::Cast(_, Some(mut writer))) => writer.flush()?,
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+fn output_path(path: &Path, day: u32, part: u8) -> PathBuf {
+    let mut name = path.file_stem().unwrap_or_default().to_os_string();
+    name.push(format!("-day{day:02}-part{part}"));
+    if let Some(extension) = path.extension() {
+        name.push(".");
+        name.push(extension);
+    }
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn output_paths() {
+        assert_eq!(
+            output_path(Path::new("out/robots.gif"), 14, 2),
+            Path::new("out/robots-day14-part2.gif")
+        );
+        assert_eq!(
+            output_path(Path::new("frames"), 6, 1),
+            Path::new("frames-day06-part1")
+        );
+    }
+}
@@ -0,0 +1,121 @@
+//! Running something one step at a time until it either stops or starts repeating itself.
+
+/// Where a simulation starts going round in circles.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    /// How many steps it takes to get to the first state that comes round again.
+    pub tail: usize,
+    /// How many steps it takes to come back round to a state.
+    pub period: usize,
+}
+
+/// A state that changes one step at a time, like a guard walking around (day 6)
+/// or robots moving around a room (day 14).
+///
+/// `step` gives the next state, or `None` if the simulation has stopped (like the guard
+/// walking off the map). The same state always has to lead to the same next one.
+///
+/// ```
+/// # use aoc_2024::simulation::{Cycle, Simulation};
+/// // 1, 2, 4, 8, 16, 32, 64, 28, 56, 12, 24, 48, 96, 92, 84, 68, 36, 72, 44, 88, 76, 52, 4, ...
+/// let mut doubling = Simulation::new(1, |&n| Some(n * 2 % 100));
+/// assert_eq!(doubling.cycle(), Some(Cycle { tail: 2, period: 20 }));
+/// assert_eq!(doubling.state_at(1_000_000_007), Some(28));
+///
+/// let mut countdown = Simulation::new(3, |&n: &u32| n.checked_sub(1));
+/// assert_eq!(countdown.cycle(), None);
+/// assert_eq!(countdown.state_at(3), Some(0));
+/// assert_eq!(countdown.state_at(4), None);
+/// ```
+pub struct Simulation<S, F> {
+    start: S,
+    step: F,
+    /// Remembered once it's been found, since finding it takes a while.
+    cycle: Option<Option<Cycle>>,
+}
+
+impl<S, F> Simulation<S, F>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> Option<S>,
+{
+    pub fn new(start: S, step: F) -> Simulation<S, F> {
+        Simulation {
+            start,
+            step,
+            cycle: None,
+        }
+    }
+
+    pub fn start(&self) -> &S {
+        &self.start
+    }
+
+    /// Find where the states start repeating, or `None` if the simulation stops first.
+    ///
+    /// This uses Brent's algorithm, so it only ever keeps two states around, but it
+    /// does run through the tail and the first time round the cycle a few times over.
+    pub fn cycle(&mut self) -> Option<Cycle> {
+        if let Some(cycle) = self.cycle {
+            return cycle;
+        }
+        let cycle = self.find_cycle();
+        self.cycle = Some(cycle);
+        cycle
+    }
+
+    fn find_cycle(&mut self) -> Option<Cycle> {
+        // the hare looks ahead in blocks that double in size,
+        // and the tortoise waits at the start of each block
+        let mut power = 1;
+        let mut period = 1;
+        let mut tortoise = self.start.clone();
+        let mut hare = (self.step)(&self.start)?;
+        while tortoise != hare {
+            if power == period {
+                tortoise = hare.clone();
+                power *= 2;
+                period = 0;
+            }
+            hare = (self.step)(&hare)?;
+            period += 1;
+        }
+
+        // now a tortoise and a hare that are a period apart meet at the start of the cycle
+        let mut tortoise = self.start.clone();
+        let mut hare = self.start.clone();
+        for _ in 0..period {
+            hare = (self.step)(&hare)?;
+        }
+        let mut tail = 0;
+        while tortoise != hare {
+            tortoise = (self.step)(&tortoise)?;
+            hare = (self.step)(&hare)?;
+            tail += 1;
+        }
+        Some(Cycle { tail, period })
+    }
+
+    /// The states from the start onwards, until the simulation stops (if it does).
+    pub fn states(&mut self) -> impl Iterator<Item = S> + '_ {
+        let mut state = Some(self.start.clone());
+        std::iter::from_fn(move || {
+            let current = state.take()?;
+            state = (self.step)(&current);
+            Some(current)
+        })
+    }
+
+    /// The state after `n` steps, or `None` if the simulation stops before then.
+    ///
+    /// Once `n` is past the end of the tail, this skips however many whole times round
+    /// the cycle it can instead of running through them, although that means finding
+    /// the [cycle](Simulation::cycle) first if it hasn't been found already.
+    pub fn state_at(&mut self, n: usize) -> Option<S> {
+        let n = match self.cycle() {
+            Some(Cycle { tail, period }) if n > tail => tail + (n - tail) % period,
+            _ => n,
+        };
+        self.states().nth(n)
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::{anyhow, Result};
+use serde::{Deserialize, Serialize};
+use std::{any::Any, env, fmt::Display, time::Instant};
+
+/// Set this to `json` to get answers as [`Record`]s instead of plain lines.
+pub const OUTPUT_VAR: &str = "AOC_OUTPUT";
+
+/// A day's puzzle: parse the input once, then answer both parts from the parsed form.
+///
+/// Solutions take `&self` so that days with extra parameters (like day 14's room size)
+/// can carry them around.
+pub trait Solution {
+    type Parsed: 'static;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed>;
+    fn part_1(&self, parsed: &Self::Parsed) -> Result<Self::Part1>;
+    fn part_2(&self, parsed: &Self::Parsed) -> Result<Self::Part2>;
+}
+
+/// Object-safe version of [`Solution`] so that days can be picked at runtime.
+///
+/// This is implemented for every [`Solution`]; there's no reason to implement it by hand.
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+    fn part_1(&self, parsed: &dyn Any) -> Result<String>;
+    fn part_2(&self, parsed: &dyn Any) -> Result<String>;
+}
+
+fn downcast<T: 'static>(parsed: &dyn Any) -> Result<&T> {
+    parsed
+        .downcast_ref()
+        .ok_or_else(|| anyhow!("parsed input is from a different solution"))
+}
+
+impl<S: Solution> DynSolution for S {
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        Ok(Box::new(Solution::parse(self, input)?))
+    }
+
+    fn part_1(&self, parsed: &dyn Any) -> Result<String> {
+        Ok(Solution::part_1(self, downcast(parsed)?)?.to_string())
+    }
+
+    fn part_2(&self, parsed: &dyn Any) -> Result<String> {
+        Ok(Solution::part_2(self, downcast(parsed)?)?.to_string())
+    }
+}
+
+/// How answers get printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Just the answer to each part on its own line.
+    #[default]
+    Text,
+    /// One [`Record`] per part as a line of JSON (so NDJSON overall).
+    Json,
+}
+
+impl OutputFormat {
+    /// Whatever [`OUTPUT_VAR`] says, or [`Text`](OutputFormat::Text) if it isn't set.
+    pub fn from_env() -> Result<OutputFormat> {
+        match env::var(OUTPUT_VAR).as_deref() {
+            Err(env::VarError::NotPresent) | Ok("" | "text") => Ok(OutputFormat::Text),
+            Ok("json") => Ok(OutputFormat::Json),
+            _ => Err(anyhow!("{OUTPUT_VAR} should be text or json")),
+        }
+    }
+}
+
+/// The answer to one part, for when something other than a person is reading it.
+///
+/// ```
+/// # use aoc_2024::solution::Record;
+/// let record = Record { day: 1, part: 2, answer: "31".into(), elapsed_ns: 1500 };
+/// assert_eq!(
+///     record.to_json(),
+///     r#"{"day":1,"part":2,"answer":"31","elapsed_ns":1500}"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Record {
+    pub day: u32,
+    pub part: u8,
+    /// Always a string, since some answers are pictures or several lines long.
+    pub answer: String,
+    /// How long the part took, not counting parsing.
+    pub elapsed_ns: u64,
+}
+
+impl Record {
+    /// Answer `part` (1 or 2) of `day` from input that `solution` has already parsed.
+    pub fn solve(
+        solution: &dyn DynSolution,
+        parsed: &dyn Any,
+        day: u32,
+        part: u8,
+    ) -> Result<Record> {
+        let start = Instant::now();
+        let answer = match part {
+            1 => solution.part_1(parsed)?,
+            2 => solution.part_2(parsed)?,
+            _ => return Err(anyhow!("there's no part {part}")),
+        };
+        Ok(Record {
+            day,
+            part,
+            answer,
+            elapsed_ns: start.elapsed().as_nanos() as u64,
+        })
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("records are always valid JSON")
+    }
+
+    pub fn print(&self, format: OutputFormat) {
+        match format {
+            OutputFormat::Text => println!("{}", self.answer),
+            OutputFormat::Json => println!("{}", self.to_json()),
+        }
+    }
+}
+
+/// Parse the input and print the answer to each part as soon as it's ready.
+///
+/// Only answers go to stdout, so anything else (like errors) has to go to stderr.
+pub fn run(solution: &dyn DynSolution, day: u32, input: &str, format: OutputFormat) -> Result<()> {
+    let parsed = solution.parse(input)?;
+    for part in [1, 2] {
+        Record::solve(solution, &*parsed, day, part)?.print(format);
+    }
+    Ok(())
+}
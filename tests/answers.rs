@@ -0,0 +1,216 @@
+//! Checks that every day still gets the answers it used to.
+//!
+//! For each `src/bin/NN/NAME.txt` input that has a `NAME.expected` file next to it, this runs
+//! day NN on it and compares both answers with what's in the `.expected` file, which looks like
+//!
+//! ```text
+//! --- part 1 ---
+//! 36
+//! --- part 2 ---
+//! 81
+//! ```
+//!
+//! Options (like `--width 11 --height 7` for day 14's example) go in `NAME.args`,
+//! separated by whitespace. Leave out the part 2 section for inputs where that answer
+//! doesn't mean anything (like day 14's example, which has no tree), and it won't be checked.
+//!
+//! Set `AOC_BLESS` to write the answers into the `.expected` files instead of checking them.
+//! To start checking a new input, create an empty `.expected` file for it and bless it.
+
+use anyhow::{anyhow, Result};
+use aoc_2024::{days, input::sidecar_args};
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+const BLESS_VAR: &str = "AOC_BLESS";
+const PART_HEADERS: [&str; 2] = ["--- part 1 ---\n", "--- part 2 ---\n"];
+
+struct Case {
+    day: u32,
+    input: PathBuf,
+    expected: PathBuf,
+    args: Vec<String>,
+}
+
+fn find_cases(bin_dir: &Path) -> Result<Vec<Case>> {
+    let mut cases = Vec::new();
+    for day_dir in fs::read_dir(bin_dir)? {
+        let day_dir = day_dir?.path();
+        let Some(day) = day_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.parse().ok())
+        else {
+            // not a day, like the aoc binary
+            continue;
+        };
+        for file in fs::read_dir(&day_dir)? {
+            let input = file?.path();
+            let expected = input.with_extension("expected");
+            if input.extension().is_none_or(|extension| extension != "txt") || !expected.exists() {
+                continue;
+            }
+            let args = sidecar_args(&input)?;
+            cases.push(Case {
+                day,
+                input,
+                expected,
+                args,
+            });
+        }
+    }
+    cases.sort_by(|a, b| (a.day, &a.input).cmp(&(b.day, &b.input)));
+    Ok(cases)
+}
+
+fn answers(case: &Case) -> Result<[String; 2]> {
+    let solution = days::get(case.day, &case.args)?;
+    let parsed = solution.parse(&fs::read_to_string(&case.input)?)?;
+    Ok([solution.part_1(&*parsed)?, solution.part_2(&*parsed)?])
+}
+
+/// Only the parts in `checked` get written.
+fn format_expected(answers: &[String; 2], checked: [bool; 2]) -> String {
+    let mut expected = String::new();
+    for ((header, answer), checked) in PART_HEADERS.iter().zip(answers).zip(checked) {
+        if !checked {
+            continue;
+        }
+        expected.push_str(header);
+        expected.push_str(answer);
+        expected.push('\n');
+    }
+    expected
+}
+
+/// `None` for a part that isn't checked.
+fn parse_expected(expected: &str) -> Result<[Option<String>; 2]> {
+    let rest = expected.strip_prefix(PART_HEADERS[0]).ok_or_else(|| {
+        anyhow!("expected file should have a part 1 section and then maybe a part 2 section")
+    })?;
+    let (part_1, part_2) = match rest.split_once(&format!("\n{}", PART_HEADERS[1])) {
+        Some((part_1, part_2)) => (part_1, Some(part_2)),
+        None => (rest, None),
+    };
+    let trim = |part: &str| part.strip_suffix('\n').unwrap_or(part).to_string();
+    if part_2.is_none() && rest.contains(PART_HEADERS[1].trim_end()) {
+        return Err(anyhow!("part 2 should come after part 1"));
+    }
+    Ok([Some(trim(part_1)), part_2.map(trim)])
+}
+
+/// Line by line, in case an answer is a long picture that's mostly the same.
+fn diff(expected: &str, actual: &str) -> String {
+    let (expected, actual): (Vec<_>, Vec<_>) =
+        (expected.lines().collect(), actual.lines().collect());
+    let mut diff = String::new();
+    for line in 0..expected.len().max(actual.len()) {
+        let (expected, actual) = (expected.get(line), actual.get(line));
+        if expected != actual {
+            if let Some(expected) = expected {
+                writeln!(diff, "  {:>4} - {expected}", line + 1).unwrap();
+            }
+            if let Some(actual) = actual {
+                writeln!(diff, "  {:>4} + {actual}", line + 1).unwrap();
+            }
+        }
+    }
+    diff
+}
+
+/// What's wrong with a case, if anything.
+fn check(case: &Case, bless: bool) -> Result<Option<String>> {
+    let actual = answers(case)?;
+    let contents = fs::read_to_string(&case.expected)?;
+    if bless {
+        // a new file checks both parts, and a part that's been left out stays out
+        let checked = match contents.as_str() {
+            "" => [true; 2],
+            contents => parse_expected(contents)?.map(|part| part.is_some()),
+        };
+        fs::write(&case.expected, format_expected(&actual, checked))?;
+        return Ok(None);
+    }
+    let expected = parse_expected(&contents)?;
+    let mut report = String::new();
+    for (part, (expected, actual)) in expected.iter().zip(&actual).enumerate() {
+        let Some(expected) = expected else {
+            continue;
+        };
+        if expected != actual {
+            writeln!(report, "part {}:", part + 1)?;
+            report.push_str(&diff(expected, actual));
+        }
+    }
+    Ok((!report.is_empty()).then_some(report))
+}
+
+#[test]
+fn stored_answers() {
+    let bin_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/bin");
+    let bless = env::var_os(BLESS_VAR).is_some();
+    let cases = find_cases(&bin_dir).unwrap();
+    assert!(
+        !cases.is_empty(),
+        "no .expected files in {}",
+        bin_dir.display()
+    );
+
+    let mut failures = String::new();
+    for case in &cases {
+        let report = match check(case, bless) {
+            Ok(None) => continue,
+            Ok(Some(report)) => report,
+            Err(err) => format!("error: {err:#}\n"),
+        };
+        let input = case.input.strip_prefix(&bin_dir).unwrap_or(&case.input);
+        writeln!(failures, "day {} with {}:", case.day, input.display()).unwrap();
+        failures.push_str(&report);
+    }
+    assert!(
+        failures.is_empty(),
+        "answers changed (set {BLESS_VAR} if that's on purpose)\n{failures}"
+    );
+}
+
+#[test]
+fn expected_files_round_trip() {
+    let answers = ["36".to_string(), "#.\n.#\n".to_string()];
+    let expected = format_expected(&answers, [true; 2]);
+    assert_eq!(expected, "--- part 1 ---\n36\n--- part 2 ---\n#.\n.#\n\n");
+    assert_eq!(
+        parse_expected(&expected).unwrap(),
+        answers.clone().map(Some)
+    );
+    assert_eq!(
+        parse_expected("--- part 1 ---\n1\n--- part 2 ---\n2").unwrap(),
+        [Some("1".into()), Some("2".into())]
+    );
+
+    let expected = format_expected(&answers, [true, false]);
+    assert_eq!(expected, "--- part 1 ---\n36\n");
+    assert_eq!(
+        parse_expected(&expected).unwrap(),
+        [Some("36".into()), None]
+    );
+
+    for bad in [
+        "",
+        "1\n2\n",
+        "--- part 2 ---\n2\n--- part 1 ---\n1\n",
+        "--- part 1 ---\n1\n--- part 2 ---",
+    ] {
+        assert!(parse_expected(bad).is_err(), "{bad:?}");
+    }
+}
+
+#[test]
+fn diff_shows_changed_lines() {
+    assert_eq!(diff("a\nb\nc", "a\nb\nc"), "");
+    assert_eq!(diff("a\nb\nc", "a\nx\nc"), "     2 - b\n     2 + x\n");
+    assert_eq!(diff("a", "a\nb"), "     2 + b\n");
+    assert_eq!(diff("a\nb", "a"), "     2 - b\n");
+}
@@ -1,18 +1,62 @@
 import { useEffect, useRef, useState } from "react";
-import { ExitMessage, InitMessage, OutputMessage } from "./communication";
+import {
+  AnswerMessage,
+  ExitMessage,
+  InitMessage,
+  OptionSpec,
+  OutputMessage,
+} from "./communication";
 import { formatSpace, formatTime } from "./formatting";
 
 export default function App() {
   const [isRunning, setIsRunning] = useState(false);
   const [program, setProgram] = useState("01");
   const [input, setInput] = useState("");
-  const [output, setOutput] = useState<OutputMessage[]>([]);
+  const [output, setOutput] = useState<(OutputMessage | AnswerMessage)[]>(
+    [],
+  );
   const [exitStats, setExitStats] = useState<ExitMessage | "Terminated">();
 
-  const [boardSize, setBoardSize] = useState("standard");
+  const [optionSpecs, setOptionSpecs] = useState<OptionSpec[]>([]);
+  const [optionValues, setOptionValues] = useState<Record<string, string>>(
+    {},
+  );
 
   const stopRef = useRef<() => void>();
 
+  // the programs say what options they take when asked for --help
+  useEffect(() => {
+    setOptionSpecs([]);
+    setOptionValues({});
+    const worker = new Worker(new URL("./worker", import.meta.url), {
+      type: "module",
+    });
+    worker.addEventListener(
+      "message",
+      ({ data }: MessageEvent<OutputMessage | AnswerMessage | ExitMessage>) => {
+        if ("line" in data && data.stream === "stdout") {
+          const specs = JSON.parse(data.line) as OptionSpec[];
+          setOptionSpecs(specs);
+          setOptionValues(
+            Object.fromEntries(
+              specs.map((spec) => [spec.name, spec.default ?? ""]),
+            ),
+          );
+        } else if ("exitCode" in data) {
+          worker.terminate();
+        }
+      },
+    );
+    worker.postMessage({
+      program,
+      input: "",
+      extraArgs: ["--help"],
+    } satisfies InitMessage);
+    return () => {
+      worker.terminate();
+    };
+  }, [program]);
+
   function run() {
     stopRef.current?.();
     setIsRunning(true);
@@ -22,7 +66,9 @@ export default function App() {
     const worker = new Worker(new URL("./worker", import.meta.url), {
       type: "module",
     });
-    const listener = ({ data }: MessageEvent<OutputMessage | ExitMessage>) => {
+    const listener = ({
+      data,
+    }: MessageEvent<OutputMessage | AnswerMessage | ExitMessage>) => {
       if ("exitCode" in data) {
         setExitStats(data);
         setIsRunning(false);
@@ -34,16 +80,9 @@ export default function App() {
     worker.postMessage({
       program,
       input,
-      extraArgs:
-        program === "14"
-          ? boardSize === "example"
-            ? ["11", "7"]
-            : ["101", "103"]
-          : program === "18"
-            ? boardSize === "example"
-              ? ["6", "12"]
-              : ["70", "1024"]
-            : [],
+      extraArgs: optionSpecs.flatMap(({ name }) =>
+        optionValues[name] ? [`--${name}`, optionValues[name]] : [],
+      ),
     } satisfies InitMessage);
 
     stopRef.current = () => {
@@ -107,57 +146,22 @@ export default function App() {
           </select>
         </label>
       </p>
-      {program === "14" && (
-        <>
-          <p className="warning">
-            Warning: My implementation for day 14 part 2 is to output all of the
-            images until it finds a cycle, at which point I can Ctrl-F through
-            the output for a bunch of asterisks in a row.{" "}
-            <strong>
-              This is far more than enough output to crash a browser tab.
-            </strong>{" "}
-            If you want to use this for part 1, be sure to hit the Stop button
-            pretty quickly after hitting the Run button. At some point I might
-            revisit this and try to make a more browser-friendly implementation
-            but today I can’t be bothered, this was really dumb. I’m already
-            annoyed that I have to have the extra room size dropdown.
-          </p>
-          <p>
-            <label>
-              Room size:{" "}
-              <select
-                value={boardSize}
-                onInput={({ currentTarget }) => {
-                  setBoardSize(currentTarget.value);
-                }}
-              >
-                <option value="standard">Standard (101 x 103)</option>
-                <option value="example">Example (11 x 7)</option>
-              </select>
-            </label>
-          </p>
-        </>
-      )}
-      {program === "18" && (
-        <p>
+      {optionSpecs.map((spec) => (
+        <p key={spec.name}>
           <label>
-            Memory size:{" "}
-            <select
-              value={boardSize}
+            {spec.help}:{" "}
+            <input
+              disabled={isRunning}
+              placeholder={spec.value}
+              value={optionValues[spec.name] ?? ""}
               onInput={({ currentTarget }) => {
-                setBoardSize(currentTarget.value);
+                const value = currentTarget.value;
+                setOptionValues((values) => ({ ...values, [spec.name]: value }));
               }}
-            >
-              <option value="standard">
-                Standard (70 x 70, first 1024 for part 1)
-              </option>
-              <option value="example">
-                Example (6 x 6, first 12 for part 1)
-              </option>
-            </select>
+            />
           </label>
         </p>
-      )}
+      ))}
       <label>
         <p>Input:</p>
         <div>
@@ -179,11 +183,19 @@ export default function App() {
         </button>
       </p>
       <ul className="output">
-        {output.map(({ line, stream }, i) => (
-          <li key={i} className={stream}>
-            {line}
-          </li>
-        ))}
+        {output.map((message, i) =>
+          "record" in message ? (
+            <li key={i}>
+              Part {message.record.part} (
+              {formatTime(message.record.elapsed_ns / 1_000_000)}):{"\n"}
+              {message.record.answer}
+            </li>
+          ) : (
+            <li key={i} className={message.stream}>
+              {message.line}
+            </li>
+          ),
+        )}
       </ul>
       {exitStats === "Terminated" ? (
         <p className="stderr">Terminated</p>
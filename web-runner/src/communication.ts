@@ -9,8 +9,28 @@ export interface OutputMessage {
   readonly stream: "stdout" | "stderr";
 }
 
+/** What the programs print for each part when `AOC_OUTPUT` is `json`. */
+export interface AnswerRecord {
+  readonly day: number;
+  readonly part: number;
+  readonly answer: string;
+  readonly elapsed_ns: number;
+}
+
+export interface AnswerMessage {
+  readonly record: AnswerRecord;
+}
+
 export interface ExitMessage {
   readonly exitCode: number;
   readonly duration: number;
   readonly memorySize: number;
 }
+
+/** An option a program takes, which it lists when run with `--help`. */
+export interface OptionSpec {
+  readonly name: string;
+  readonly value: string;
+  readonly help: string;
+  readonly default: string | null;
+}
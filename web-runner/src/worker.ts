@@ -26,7 +26,13 @@ import wasm17 from "../../target/wasm32-wasip1/release/17.wasm?init";
 import wasm18 from "../../target/wasm32-wasip1/release/18.wasm?init";
 import wasm19 from "../../target/wasm32-wasip1/release/19.wasm?init";
 import wasm20 from "../../target/wasm32-wasip1/release/20.wasm?init";
-import { ExitMessage, InitMessage, OutputMessage } from "./communication";
+import {
+  AnswerMessage,
+  AnswerRecord,
+  ExitMessage,
+  InitMessage,
+  OutputMessage,
+} from "./communication";
 
 const programs = {
   "01": wasm01,
@@ -59,13 +65,19 @@ addEventListener(
   }: MessageEvent<InitMessage>) => {
     if (program in programs) {
       const args = [program, "input.txt", ...extraArgs];
-      const env: string[] = [];
+      // every line of stdout is an AnswerRecord, and anything else goes to stderr
+      const env = ["AOC_OUTPUT=json"];
       const fds = [
         // stdin
         new OpenFile(new File([], { readonly: true })),
         // stdout
         ConsoleStdout.lineBuffered((line) => {
-          postMessage({ line, stream: "stdout" } satisfies OutputMessage);
+          const record = parseRecord(line);
+          if (record) {
+            postMessage({ record } satisfies AnswerMessage);
+          } else {
+            postMessage({ line, stream: "stdout" } satisfies OutputMessage);
+          }
         }),
         // stderr
         ConsoleStdout.lineBuffered((line) => {
@@ -112,6 +124,21 @@ addEventListener(
   { once: true },
 );
 
+function parseRecord(line: string): AnswerRecord | undefined {
+  try {
+    const record = JSON.parse(line) as Partial<AnswerRecord> | null;
+    if (
+      typeof record?.part === "number" &&
+      typeof record.answer === "string"
+    ) {
+      return record as AnswerRecord;
+    }
+  } catch {
+    // not JSON, so just an ordinary line
+  }
+  return undefined;
+}
+
 function addTrailingNewline(content: string): string {
   if (!content.endsWith("\n")) {
     return content + "\n";